@@ -77,6 +77,14 @@ struct IRValue {
     int bitfieldOffset = 0;          
     int bitfieldWidth = 0;           
     bool bitfieldIsUnsigned = true;  
+
+    // EN: Accesses through this value must use volatile loads/stores.
+    // FR: Les acces via cette valeur doivent utiliser des load/store volatiles.
+    bool isVolatile = false;
+
+    // EN: The i1 this i32 was widened from, so conditions branch on it without re-testing.
+    // FR: Le i1 dont ce i32 est issu, pour que les conditions branchent dessus sans re-test.
+    std::string boolName;
     
     // EN: Default constructor for an empty IR value.
     // FR: Constructeur par defaut pour une valeur IR vide.
@@ -249,6 +257,15 @@ public:
     // EN: Visits an initializer list expression.
     // FR: Visite une liste d initialisation.
     void visit(AST::InitializerList& node) override;
+    // EN: Visits a va_arg expression.
+    // FR: Visite une expression va_arg.
+    void visit(AST::VaArgExpr& node) override;
+    // EN: Returns the address a va_* intrinsic or va_arg operates on.
+    // FR: Renvoie l adresse sur laquelle operent va_arg et les intrinseques va_*.
+    IRValue emitVaListAddress(AST::Expression* list);
+    // EN: Lowers __builtin_va_start/end/copy calls; returns false for other callees.
+    // FR: Abaisse les appels __builtin_va_start/end/copy; renvoie false sinon.
+    bool emitVaBuiltinCall(AST::CallExpr& node, const std::string& name);
 
     
     
@@ -283,8 +300,20 @@ public:
     // EN: Strips qualifiers/typedefs to a base type.
     // FR: Retire qualifiers/typedefs vers un type de base.
     AST::Type* stripQualifiers(AST::Type* type);
+    // EN: Checks whether an object of the given type is const-qualified.
+    // FR: Verifie si un objet du type donne est qualifie const.
+    bool isConstObjectType(AST::Type* type);
+    // EN: Checks whether an object of the given type is volatile-qualified.
+    // FR: Verifie si un objet du type donne est qualifie volatile.
+    bool isVolatileObjectType(AST::Type* type);
+    // EN: Returns the element count of an array type, or -1 when unknown.
+    // FR: Renvoie le nombre d elements d un tableau, ou -1 si inconnu.
+    long long getArrayLength(AST::ArrayType* arrayType);
+    // EN: Resolves a tag reference to its complete struct/union definition.
+    // FR: Resout une reference de tag vers la definition complete de struct/union.
+    AST::StructType* resolveStructDefinition(AST::StructType* structType);
+
 
-    
     
     
     // EN: Allocates a new SSA temporary name.
@@ -296,6 +325,9 @@ public:
     // EN: Emits a global string and returns its symbol name.
     // FR: Emet une string globale et renvoie son symbole.
     std::string newGlobalString(const std::string& str);
+    // EN: Escapes raw bytes for an LLVM c"..." constant.
+    // FR: Echappe des octets bruts pour une constante LLVM c"...".
+    std::string escapeIRString(const std::string& str);
     
     // EN: Emits an instruction to the current buffer.
     // FR: Emet une instruction dans le buffer courant.
@@ -309,6 +341,9 @@ public:
     // EN: Emits a global definition line.
     // FR: Emet une ligne de definition globale.
     void emitGlobal(const std::string& ir);
+    // EN: Replaces an emitted global line (extern or tentative) with its definition.
+    // FR: Remplace une ligne globale emise (extern ou provisoire) par sa definition.
+    void replaceGlobalLine(const std::string& oldLine, const std::string& newLine);
     
     
     
@@ -319,6 +354,21 @@ public:
     // EN: Stores a value into a pointer location.
     // FR: Stocke une valeur dans un pointeur.
     IRValue storeValue(const IRValue& val, const IRValue& ptr);
+    // EN: Decays a pointer-to-array value to a pointer to its first element, and a function to its address.
+    // FR: Degrade une valeur pointeur-sur-tableau en pointeur sur son premier element, et une fonction en son adresse.
+    IRValue decayArrayValue(const IRValue& val);
+    // EN: Copies an aggregate object from src to dst with llvm.memcpy.
+    // FR: Copie un objet agrege de src vers dst avec llvm.memcpy.
+    void emitAggregateCopy(const IRValue& dst, const IRValue& src, AST::Type* type);
+    // EN: Converts a loaded scalar value to the target LLVM type.
+    // FR: Convertit une valeur scalaire chargee vers le type LLVM cible.
+    IRValue convertScalarValue(const IRValue& val, const std::string& targetType, bool isUnsigned);
+    // EN: Emits (or reuses) the i1 truth value of a loaded scalar for branches and logical ops.
+    // FR: Emet (ou reutilise) la valeur de verite i1 d un scalaire charge pour branches et ops logiques.
+    std::string emitTruthValue(const IRValue& loaded);
+    // EN: Widens an i1 to an int 0/1, remembering the i1 for later conditions.
+    // FR: Elargit un i1 en int 0/1 en memorisant le i1 pour les conditions suivantes.
+    IRValue widenTruthValue(const std::string& boolReg);
     
     
     
@@ -332,6 +382,9 @@ public:
     // EN: Looks up a symbol by name.
     // FR: Cherche un symbole par nom.
     IRSymbol* lookupSymbol(const std::string& name);
+    // EN: Returns the function symbol named by `f` or `&f`, or nullptr.
+    // FR: Renvoie le symbole de fonction designe par `f` ou `&f`, ou nullptr.
+    IRSymbol* functionDesignatorSymbol(AST::Expression* expr);
     // EN: Defines a symbol in the current scope.
     // FR: Definit un symbole dans le scope courant.
     void defineSymbol(const std::string& name, const IRSymbol& sym);
@@ -342,6 +395,9 @@ public:
     // EN: Resolves a typedef name to a concrete type.
     // FR: Resout un typedef vers un type concret.
     AST::Type* resolveTypedef(const std::string& name);
+    // EN: Resolves a typedef use, preferring the binding captured by the parser.
+    // FR: Resout un usage de typedef, en preferant la liaison capturee par le parseur.
+    AST::Type* resolveTypedef(AST::TypedefType* type);
     
     
     
@@ -353,8 +409,10 @@ public:
         std::map<std::string, int> bitfieldOffsets;  
         std::map<std::string, int> bitfieldWidths;   
         std::map<std::string, bool> bitfieldIsUnsigned;  
+        std::set<std::string> volatileMembers;
         int totalSize = 0;
         int alignment = 1;
+        int fieldCount = 0;
         bool isUnion = false;
     };
     // EN: Computes layout for a struct declaration.
@@ -440,6 +498,15 @@ private:
     // EN: Generates a flattened struct initializer from nested lists.
     // FR: Genere un init de struct flatten depuis listes imbriquees.
     std::string generateStructFromInitForFlattened(AST::StructType* stype, AST::InitializerList* typeInitList);
+    // EN: Generates a constant for one scalar element, zero when not foldable.
+    // FR: Genere la constante d un element scalaire, zero si non evaluable.
+    std::string generateScalarInitializerValue(AST::Type* type, AST::Expression* expr);
+    // EN: Generates a char array constant from a string literal, zero-filling the tail.
+    // FR: Genere une constante tableau de char depuis une chaine, completee par des zeros.
+    std::string generateStringArrayInitializer(AST::ArrayType* arrayType, AST::StringLiteral* strLit);
+    // EN: Checks whether an array has a character element type.
+    // FR: Verifie si un tableau a un type d element caractere.
+    bool isCharArrayType(AST::ArrayType* arrayType);
 
     
     
@@ -456,6 +523,9 @@ private:
     // EN: Emits a local variable declaration.
     // FR: Emet une declaration de variable locale.
     void emitLocalVarDecl(AST::VarDecl& node, const std::string& llvmType);
+    // EN: Sizes an unsized array declaration from its initializer.
+    // FR: Dimensionne un tableau sans taille depuis son initialiseur.
+    void completeArrayFromInitializer(AST::VarDecl& node);
 
     // EN: Emits IR for assignment expressions.
     // FR: Emet l IR pour les affectations.
@@ -626,6 +696,9 @@ private:
     std::set<std::string> declaredFunctions_;
     std::set<std::string> definedFunctions_;  
     std::set<std::string> declaredGlobals_;
+    // EN: Globals emitted from an extern or tentative declaration, with their emitted line.
+    // FR: Globales emises depuis une declaration extern ou provisoire, avec leur ligne emise.
+    std::map<std::string, std::string> provisionalGlobals_;
     std::map<std::string, std::string> functionDeclarations_;  
 };
 
@@ -15,12 +15,14 @@ struct CompilerOptions {
     bool showVersion = false;
     bool syntaxOnly = false;
     bool preprocessOnly = false;  
+    bool lineMarkers = false;     
     bool debugMode = false;        
     bool debugInfo = false;        
     bool is64bit = false;          
     std::vector<std::string> defines;      
     std::vector<std::string> undefines;    
     std::vector<std::string> includePaths; 
+    std::vector<std::string> forceIncludes; 
     std::vector<std::string> ignoredOptions; 
 };
 
@@ -26,12 +26,14 @@ class CompilerDriver
         std::string display_source_;
         bool syntax_only_;
         bool preprocess_only_;
+        bool line_markers_;
         bool debug_mode_;
         bool debug_info_;
         bool is64bit_;
         std::vector<std::string> defines_;
         std::vector<std::string> undefines_;
         std::vector<std::string> include_paths_;
+        std::vector<std::string> force_includes_;
         std::unique_ptr<AST::TranslationUnit> ast_;
         std::unique_ptr<SymbolTable> symbols_;
         std::vector<Token> tokens_;
@@ -34,6 +34,10 @@ public:
     // EN: Reports whether a parse error occurred.
     // FR: Indique si une erreur de parsing est survenue.
     bool hadError() const { return hadError_; }
+    
+    // EN: Binds compiler-provided typedefs (__builtin_va_list) for the target.
+    // FR: Lie les typedefs fournis par le compilateur (__builtin_va_list) pour la cible.
+    void declareBuiltinTypedefs(bool is64bit);
 
 private:
     
@@ -141,6 +145,12 @@ private:
     // EN: Checks if the current token is a type qualifier.
     // FR: Verifie si le token courant est un type qualifier.
     bool isTypeQualifier() const;
+    // EN: Checks if the current token is __cdecl, __stdcall or __declspec.
+    // FR: Verifie si le token courant est __cdecl, __stdcall ou __declspec.
+    bool isIgnoredSpecifier() const;
+    // EN: Skips one ignored specifier, including a balanced __declspec(...) group.
+    // FR: Saute un specifier ignore, y compris un groupe __declspec(...) equilibre.
+    void skipIgnoredSpecifier();
     
     // EN: Parses a declarator with a given base type.
     // FR: Parse un declarator avec un type de base.
@@ -325,6 +335,23 @@ private:
     // EN: Checks if the current token begins a declaration.
     // FR: Verifie si le token courant commence une declaration.
     bool isDeclarationStart() const;
+
+    // EN: Checks whether a name currently denotes a typedef (inner bindings win).
+    // FR: Verifie si un nom designe actuellement un typedef (les liaisons internes gagnent).
+    bool isTypedefName(const std::string& name) const;
+    // EN: Returns a copy of the type a visible typedef name stands for.
+    // FR: Renvoie une copie du type designe par un nom de typedef visible.
+    AST::Ptr<AST::Type> typedefUnderlyingType(const std::string& name) const;
+    // EN: Binds a typedef name in the innermost scope.
+    // FR: Lie un nom de typedef dans le scope le plus interne.
+    void declareTypedefName(const std::string& name, const AST::Type* type);
+    // EN: Binds an ordinary identifier, hiding outer typedefs of the same name.
+    // FR: Lie un identifiant ordinaire, masquant les typedefs externes du meme nom.
+    void declareOrdinaryName(const std::string& name);
+    // EN: Opens/closes a block scope for typedef name bindings.
+    // FR: Ouvre/ferme un scope de bloc pour les liaisons de noms typedef.
+    void pushTypedefScope();
+    void popTypedefScope();
     
     // EN: Maps token types to AST binary operators.
     // FR: Mappe les tokens vers operateurs binaires AST.
@@ -354,7 +381,9 @@ private:
     bool hadError_ = false;
     
     
-    std::set<std::string> typedefNames_;
+    // EN: Typedef bindings per scope; a null type marks an ordinary identifier.
+    // FR: Liaisons typedef par scope; un type nul marque un identifiant ordinaire.
+    std::vector<std::map<std::string, AST::Ptr<AST::Type>>> typedefScopes_;
     
     
     std::map<std::string, std::string> globalIdentifiers_;
@@ -367,6 +396,7 @@ private:
     
     
     int switchDepth_ = 0;     
+    int loopDepth_ = 0;       
     int functionDepth_ = 0;   
 };
 
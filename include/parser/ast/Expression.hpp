@@ -362,6 +362,23 @@ public:
     void accept(Visitor& visitor) override;
 };
 
+// EN: va_arg expression fetching the next variadic argument of a type.
+// FR: Expression va_arg lisant le prochain argument variadique d un type.
+class VaArgExpr : public Expression {
+public:
+    Ptr<Expression> list;
+    Ptr<Type> targetType;
+    
+    // EN: Builds a va_arg expression node.
+    // FR: Construit un noeud d expression va_arg.
+    VaArgExpr(Ptr<Expression> ap, Ptr<Type> type, int l, int c)
+        : Expression(l, c), list(std::move(ap)), targetType(std::move(type)) {}
+    
+    // EN: Accepts a visitor for this va_arg expression.
+    // FR: Accepte un visiteur pour cette expression va_arg.
+    void accept(Visitor& visitor) override;
+};
+
 // TODO(cc1) EN: Add missing expression nodes (compound literals, designators).
 // FR: Ajouter les noeuds d expression manquants (litteraux composes, designateurs).
 } 
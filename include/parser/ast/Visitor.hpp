@@ -63,6 +63,9 @@ public:
     // EN: Visits initializer list nodes.
     // FR: Visite les noeuds de liste d initialisation.
     virtual void visit(InitializerList& node) = 0;
+    // EN: Visits va_arg expression nodes.
+    // FR: Visite les noeuds d expression va_arg.
+    virtual void visit(VaArgExpr& node) = 0;
     
     
     // EN: Visits compound statement nodes.
@@ -154,6 +157,7 @@ public:
     void visit(IndexExpr&) override {}
     void visit(TernaryExpr&) override {}
     void visit(InitializerList&) override {}
+    void visit(VaArgExpr&) override {}
     
     void visit(CompoundStmt&) override {}
     void visit(ExpressionStmt&) override {}
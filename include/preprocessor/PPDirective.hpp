@@ -205,6 +205,21 @@ public:
     void accept(DirectiveVisitor& visitor) override;
 };
 
+// EN: Represents a nonstandard directive such as #assert, kept only for diagnostics.
+// FR: Represente une directive non standard comme #assert, gardee pour les diagnostics.
+class UnknownDirective : public Directive {
+public:
+    std::string name;
+    
+    // EN: Builds an unknown directive node from its keyword.
+    // FR: Construit un noeud de directive inconnue depuis son mot-cle.
+    explicit UnknownDirective(const std::string& n) : name(n) {}
+    
+    // EN: Dispatches to the visitor for unknown directive handling.
+    // FR: Dispatch vers le visiteur pour une directive inconnue.
+    void accept(DirectiveVisitor& visitor) override;
+};
+
 
 
 
@@ -250,6 +265,9 @@ public:
     // EN: Visits a #line directive node.
     // FR: Visite un noeud #line.
     virtual void visit(LineDirective& dir) = 0;
+    // EN: Visits an unknown directive node.
+    // FR: Visite un noeud de directive inconnue.
+    virtual void visit(UnknownDirective& dir) = 0;
 };
 
 // TODO(cc1) EN: Add directive source span metadata for richer diagnostics.
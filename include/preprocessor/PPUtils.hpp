@@ -41,6 +41,13 @@ public:
     // EN: Strips comments from a full source buffer, keeping newlines intact.
     // FR: Retire les commentaires d un buffer en gardant les lignes.
     std::string remove(const std::string& source);
+    
+    // EN: Line where the last buffer left a /* comment open, or 0 if all were closed.
+    // FR: Ligne ou le dernier buffer a laisse un /* ouvert, ou 0 si tous sont fermes.
+    int unterminatedCommentLine() const { return unterminatedCommentLine_; }
+
+private:
+    int unterminatedCommentLine_ = 0;
 };
 
 
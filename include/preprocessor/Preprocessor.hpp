@@ -61,6 +61,12 @@ public:
     std::string preprocessString(const std::string& source, const std::string& filename = "<string>");
     
     
+    // EN: Preprocesses a header named by -include before the main source,
+    // sharing the macro table. FR: Pretraite un en-tete donne par -include
+    // avant la source principale, en partageant la table des macros.
+    std::string preprocessForcedInclude(const std::string& filename);
+    
+    
     // EN: Adds an include search path so projects control header resolution.
     // FR: Ajoute un chemin de recherche pour controler la resolution des
     // en-tetes.
@@ -78,6 +84,12 @@ public:
     void undefineMacro(const std::string& name);
     
     
+    // EN: Enables GNU-style '# line "file" flags' markers in the output so
+    // preprocessed text maps back to source positions. FR: Active les marqueurs
+    // GNU '# ligne "fichier" flags' pour relier la sortie aux positions source.
+    void setLineMarkers(bool enabled) { lineMarkers_ = enabled; }
+    
+    
     // EN: Reports whether an error occurred so the driver can stop early.
     // FR: Indique si une erreur est survenue pour arreter tot.
     bool hadError() const { return hadError_; }
@@ -115,6 +127,9 @@ public:
     // EN: Handles #line to remap logical filename and line numbers.
     // FR: Traite #line pour remapper fichier logique et numeros de ligne.
     void visit(pp::LineDirective& dir) override;
+    // EN: Warns about a nonstandard directive and ignores it.
+    // FR: Avertit d une directive non standard et l ignore.
+    void visit(pp::UnknownDirective& dir) override;
     
 private:
     
@@ -132,6 +147,9 @@ private:
     bool hadError_ = false;
     std::stack<ConditionalState> conditionalStack_;
     std::string* currentOutput_ = nullptr;  
+    bool lineMarkers_ = false;
+    bool lineMarkerPending_ = false;
+    std::string lineMarkerFlag_;
     
     
     // EN: Loads and preprocesses a file, centralizing IO and error reporting.
@@ -145,6 +163,15 @@ private:
     std::string processLine(const std::string& line);
     
     
+    // EN: Formats a line marker, e.g. '# 12 "foo.h" 2'; flag may be empty.
+    // FR: Formate un marqueur de ligne, ex. '# 12 "foo.h" 2'; flag optionnel.
+    std::string lineMarker(int line, const std::string& filename, const std::string& flag) const;
+    // EN: Requests a marker before the next emitted line (after includes or
+    // dropped lines). FR: Demande un marqueur avant la prochaine ligne emise
+    // (apres un include ou des lignes supprimees).
+    void requestLineMarker(const std::string& flag);
+    
+    
     // EN: Returns whether current conditional stack allows output.
     // FR: Indique si la pile conditionnelle autorise la sortie.
     bool isActive() const;
@@ -153,11 +180,17 @@ private:
     // EN: Initializes builtin macros like __FILE__/__LINE__ to match compilers.
     // FR: Initialise les macros internes pour coller aux compilateurs.
     void setupBuiltinMacros();
+    // EN: Strips comments from a file buffer and reports a /* left open at EOF.
+    // FR: Retire les commentaires d un buffer et signale un /* reste ouvert en fin.
+    std::string removeComments(const std::string& source, const std::string& filename);
     
     
     // EN: Emits an error at the current logical location and marks failure.
     // FR: Emet une erreur a l emplacement logique et marque l echec.
     void error(const std::string& message);
+    // EN: Emits an error at an explicit file and line and marks failure.
+    // FR: Emet une erreur a un fichier et une ligne donnes et marque l echec.
+    void errorAt(const std::string& file, int line, const std::string& message);
     // EN: Emits a warning at the current logical location without stopping.
     // FR: Emet un avertissement a l emplacement logique sans arreter.
     void warning(const std::string& message);
@@ -165,6 +165,9 @@ public:
     // EN: Visits cast expressions to check conversion validity.
     // FR: Visite les casts pour verifier la conversion.
     void visit(AST::CastExpr& node) override;
+    // EN: Visits va_arg expressions, typing them as the fetched type.
+    // FR: Visite les va_arg, types comme le type lu.
+    void visit(AST::VaArgExpr& node) override;
     // EN: Visits call expressions to validate callee/arguments.
     // FR: Visite les appels pour valider callee/arguments.
     void visit(AST::CallExpr& node) override;
@@ -192,6 +195,9 @@ private:
     // EN: Emits a warning diagnostic.
     // FR: Emet un diagnostic d avertissement.
     void warning(int line, int column, const std::string& message);
+    // EN: Warns when 'main' is not int main(void) or int main(int, char **).
+    // FR: Avertit quand 'main' n est ni int main(void) ni int main(int, char **).
+    void checkMainSignature(AST::FunctionDecl& node);
     // EN: Returns a source line for diagnostics.
     // FR: Renvoie une ligne source pour diagnostics.
     std::string getSourceLine(int line) const;
@@ -208,6 +214,12 @@ private:
     // EN: Retrieves or computes the type of an expression.
     // FR: Recupere ou calcule le type d une expression.
     AST::Ptr<AST::Type> getExprType(AST::Expression* expr);
+    // EN: Picks an integer literal's type from its suffix and radix (u, ul, ull; hex/octal above INT_MAX).
+    // FR: Choisit le type d un litteral entier selon son suffixe et sa base (u, ul, ull; hex/octal au-dela de INT_MAX).
+    AST::PrimitiveKind integerLiteralKind(const AST::IntegerLiteral& lit) const;
+    // EN: Tells whether an expression designates an object (C has no lvalue ?:, cast or call).
+    // FR: Indique si une expression designe un objet (en C, ni ?:, ni cast, ni appel ne sont des lvalues).
+    bool isLvalueExpression(AST::Expression* expr);
     // EN: Type predicate helpers used throughout semantic checks.
     // FR: Helpers de predicates de type utilises partout.
     bool isVoidType(AST::Type* type);
@@ -222,6 +234,9 @@ private:
     // FR: Verifications de compatibilite/affectation de types.
     bool typesCompatible(AST::Type* left, AST::Type* right);
     bool canAssign(AST::Type* target, AST::Type* source, bool isInit = false);
+    // EN: Applies the usual arithmetic conversions to two arithmetic operand types.
+    // FR: Applique les conversions arithmetiques usuelles a deux types d operandes.
+    AST::Ptr<AST::Type> commonArithmeticType(AST::Type* left, AST::Type* right);
     // EN: Removes qualifiers to compare underlying types.
     // FR: Retire les qualifiers pour comparer les types sous-jacents.
     AST::Type* stripQualifiers(AST::Type* type);
@@ -235,6 +250,12 @@ private:
     bool isConstantExpr(AST::Expression* expr);
     bool evaluateConstantExpr(AST::Expression* expr, long long& result);
     bool hasDivisionByZero(AST::Expression* expr);
+    // EN: Recognizes null pointer constants (0 and (void*)0 forms).
+    // FR: Reconnait les constantes pointeur nul (formes 0 et (void*)0).
+    bool isNullPointerConstant(AST::Expression* expr);
+    // EN: Warns when a folded integer constant does not fit its destination type.
+    // FR: Avertit quand une constante entiere evaluee ne tient pas dans le type cible.
+    void checkConstantConversion(AST::Type* destType, AST::Expression* expr, int line, int column);
 
     struct ConstExprIsConstVisitor;
     struct ConstExprEvalVisitor;
@@ -258,6 +279,9 @@ private:
     
     AST::FunctionDecl* currentFunction_ = nullptr;
     bool inGlobalScope_ = true;
+    // EN: Case/default labels seen per enclosing switch, innermost last.
+    // FR: Labels case/default vus par switch englobant, le plus interne en dernier.
+    std::vector<int> switchLabelCounts_;
     
     
     std::map<AST::Expression*, AST::Ptr<AST::Type>> exprTypes_;
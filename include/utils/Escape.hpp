@@ -0,0 +1,18 @@
+#pragma once
+
+
+
+#include <string>
+
+namespace utils
+{
+    // EN: Maps the letter of a simple C89 escape (n, t, a, ...) to its byte; returns false if unknown.
+    // FR: Associe la lettre d un echappement C89 simple (n, t, a, ...) a son octet; renvoie false si inconnu.
+    bool simple_escape_value(char letter, int& value);
+    // EN: Decodes the escape sequence starting at text[pos] (a backslash) and advances pos past it.
+    // FR: Decode la sequence d echappement commencant a text[pos] (un backslash) et avance pos apres.
+    int decode_escape(const std::string& text, size_t& pos);
+    // EN: Decodes every escape sequence of a literal body into raw bytes.
+    // FR: Decode toutes les sequences d echappement d un corps de litteral en octets bruts.
+    std::string decode_escapes(const std::string& text);
+}
@@ -128,6 +128,10 @@ bool IRGenerator::evaluateConstantFloatExpr(AST::Expression* expr, double& resul
             result = static_cast<double>(getTypeSize(sizeofExpr->targetType.get()));
             return true;
         }
+        if (sizeofExpr->operand && sizeofExpr->operand->resolvedType) {
+            result = static_cast<double>(getTypeSize(sizeofExpr->operand->resolvedType.get()));
+            return true;
+        }
         return false;
     }
 
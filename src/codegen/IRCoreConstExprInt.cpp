@@ -129,7 +129,12 @@ bool IRGenerator::evaluateConstantExpr(AST::Expression* expr, long long& result)
             result = getTypeSize(sizeofExpr->targetType.get());
             return true;
         }
-        
+        // EN: Expression operands (including a nested sizeof, typed size_t) use their resolved type.
+        // FR: Les operandes expression (dont un sizeof imbrique, de type size_t) utilisent leur type resolu.
+        if (sizeofExpr->operand && sizeofExpr->operand->resolvedType) {
+            result = getTypeSize(sizeofExpr->operand->resolvedType.get());
+            return true;
+        }
         return false;
     }
 
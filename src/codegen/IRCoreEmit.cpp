@@ -47,4 +47,21 @@ void IRGenerator::emitGlobal(const std::string& ir) {
     globalBuffer_ << ir << "\n";
 }
 
+// EN: Replaces a previously emitted global line in place.
+// FR: Remplace sur place une ligne globale deja emise.
+void IRGenerator::replaceGlobalLine(const std::string& oldLine, const std::string& newLine) {
+    std::string globals = globalBuffer_.str();
+    size_t pos = globals.find(oldLine + "\n");
+    while (pos != std::string::npos && pos > 0 && globals[pos - 1] != '\n') {
+        pos = globals.find(oldLine + "\n", pos + 1);
+    }
+    if (pos == std::string::npos) {
+        emitGlobal(newLine);
+        return;
+    }
+    globals.replace(pos, oldLine.size(), newLine);
+    globalBuffer_.str(globals);
+    globalBuffer_.seekp(0, std::ios_base::end);
+}
+
 } 
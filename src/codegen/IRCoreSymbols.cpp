@@ -29,6 +29,19 @@ IRSymbol* IRGenerator::lookupSymbol(const std::string& name) {
     return nullptr;
 }
 
+// EN: Resolves `f` or `&f` to the symbol of function f.
+// FR: Resout `f` ou `&f` vers le symbole de la fonction f.
+IRSymbol* IRGenerator::functionDesignatorSymbol(AST::Expression* expr) {
+    if (auto* unary = dynamic_cast<AST::UnaryExpr*>(expr)) {
+        if (unary->op != AST::UnaryOp::AddressOf) return nullptr;
+        expr = unary->operand.get();
+    }
+    auto* id = dynamic_cast<AST::Identifier*>(expr);
+    if (!id) return nullptr;
+    IRSymbol* sym = lookupSymbol(id->name);
+    return (sym && sym->isFunction) ? sym : nullptr;
+}
+
 // EN: Defines or updates a symbol in the current scope.
 // FR: Definit ou met a jour un symbole dans le scope courant.
 void IRGenerator::defineSymbol(const std::string& name, const IRSymbol& sym) {
@@ -16,12 +16,9 @@ std::string IRGenerator::newLabel(const std::string& prefix) {
     return prefix + std::to_string(labelCounter_++);
 }
 
-// EN: Emits a global string constant and returns its symbol name.
-// FR: Emet une constante string globale et renvoie son symbole.
-std::string IRGenerator::newGlobalString(const std::string& str) {
-    std::string name = "@.str." + std::to_string(stringCounter_++);
-
-    
+// EN: Escapes raw bytes for use inside an LLVM c"..." constant.
+// FR: Echappe des octets bruts pour une constante LLVM c"...".
+std::string IRGenerator::escapeIRString(const std::string& str) {
     std::string escaped;
     for (char c : str) {
         if (c == '\\') {
@@ -45,6 +42,16 @@ std::string IRGenerator::newGlobalString(const std::string& str) {
             escaped += c;
         }
     }
+    return escaped;
+}
+
+// EN: Emits a global string constant and returns its symbol name.
+// FR: Emet une constante string globale et renvoie son symbole.
+std::string IRGenerator::newGlobalString(const std::string& str) {
+    std::string name = "@.str." + std::to_string(stringCounter_++);
+
+    
+    std::string escaped = escapeIRString(str);
 
     
     escaped += "\\00";
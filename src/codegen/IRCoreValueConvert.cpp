@@ -0,0 +1,85 @@
+#include <codegen/IRGenerator.hpp>
+
+namespace cc1 {
+
+// EN: Turns a pointer to an array into a pointer to its first element.
+// FR: Transforme un pointeur sur tableau en pointeur sur son premier element.
+IRValue IRGenerator::decayArrayValue(const IRValue& val) {
+    // EN: A function designator (type "ret (args)") already names its address; only the type gains a '*'.
+    // FR: Un designateur de fonction (type "ret (args)") nomme deja son adresse; seul le type gagne un '*'.
+    if (!val.isPointer && !val.type.empty() && val.type.back() == ')') {
+        return IRValue(val.name, val.type + "*", false, val.isConstant);
+    }
+    std::string arrayType = val.isPointer ? val.derefType() : "";
+    if (arrayType.empty() || arrayType[0] != '[' || arrayType.find(" x ") == std::string::npos) {
+        return val;
+    }
+
+    std::string elemType = arrayType.substr(arrayType.find(" x ") + 3);
+    elemType = elemType.substr(0, elemType.size() - 1);
+    std::string idxType = is64bit_ ? "i64" : "i32";
+    std::string decayed = newTemp();
+    emit(decayed + " = getelementptr inbounds " + arrayType + ", " + val.type + " " + val.name + ", " + idxType +
+         " 0, " + idxType + " 0");
+    return IRValue(decayed, elemType + "*", false, false);
+}
+
+// EN: Converts a loaded scalar to another scalar LLVM type (ints, floats, pointers).
+// FR: Convertit un scalaire charge vers un autre type LLVM scalaire (entiers, flottants, pointeurs).
+IRValue IRGenerator::convertScalarValue(const IRValue& val, const std::string& targetType, bool isUnsigned) {
+    if (val.type == targetType || targetType.empty() || targetType == "void") {
+        return val;
+    }
+
+    // EN: Maps integer LLVM types to bit-width for promotions.
+    // FR: Mappe les types entiers LLVM vers la largeur en bits.
+    auto getIntSize = [](const std::string& t) -> int {
+        if (t == "i1") return 1;
+        if (t == "i8") return 8;
+        if (t == "i16") return 16;
+        if (t == "i32") return 32;
+        if (t == "i64") return 64;
+        return 0;
+    };
+    auto isFloating = [](const std::string& t) { return t == "float" || t == "double"; };
+    auto isPtr = [](const std::string& t) { return !t.empty() && t.back() == '*'; };
+    int srcSize = getIntSize(val.type);
+    int dstSize = getIntSize(targetType);
+    if (srcSize == 1) isUnsigned = true;
+
+    
+    if (srcSize > 0 && isPtr(targetType) && val.isConstant && val.name == "0") {
+        return IRValue("null", targetType, false, true);
+    }
+
+    std::string op;
+    if (srcSize > 0 && dstSize > 0) {
+        if (srcSize > dstSize) {
+            op = "trunc";
+        } else {
+            op = isUnsigned ? "zext" : "sext";
+        }
+    } else if (srcSize > 0 && isFloating(targetType)) {
+        op = isUnsigned ? "uitofp" : "sitofp";
+    } else if (isFloating(val.type) && dstSize > 0) {
+        op = "fptosi";
+    } else if (val.type == "float" && targetType == "double") {
+        op = "fpext";
+    } else if (val.type == "double" && targetType == "float") {
+        op = "fptrunc";
+    } else if (isPtr(val.type) && isPtr(targetType)) {
+        op = "bitcast";
+    } else if (srcSize > 0 && isPtr(targetType)) {
+        op = "inttoptr";
+    } else if (isPtr(val.type) && dstSize > 0) {
+        op = "ptrtoint";
+    } else {
+        return val;
+    }
+
+    std::string converted = newTemp();
+    emit(converted + " = " + op + " " + val.type + " " + val.name + " to " + targetType);
+    return IRValue(converted, targetType, false, false);
+}
+
+} 
@@ -0,0 +1,21 @@
+#include <codegen/IRGenerator.hpp>
+
+namespace cc1 {
+
+// EN: Copies a whole aggregate object with llvm.memcpy, sized by the C type.
+// FR: Copie un objet agrege entier avec llvm.memcpy, dimensionne par le type C.
+void IRGenerator::emitAggregateCopy(const IRValue& dst, const IRValue& src, AST::Type* type) {
+    std::string sizeType = is64bit_ ? "i64" : "i32";
+    std::string intrinsic = "llvm.memcpy.p0i8.p0i8." + sizeType;
+
+    std::string dstRaw = newTemp();
+    emit(dstRaw + " = bitcast " + dst.type + " " + dst.name + " to i8*");
+    std::string srcRaw = newTemp();
+    emit(srcRaw + " = bitcast " + src.type + " " + src.name + " to i8*");
+
+    emit("call void @" + intrinsic + "(i8* " + dstRaw + ", i8* " + srcRaw + ", " + sizeType + " " +
+         std::to_string(getTypeSize(type)) + ", i1 " + (dst.isVolatile || src.isVolatile ? "true" : "false") + ")");
+    functionDeclarations_[intrinsic] = "declare void @" + intrinsic + "(i8*, i8*, " + sizeType + ", i1)\n";
+}
+
+}
@@ -11,7 +11,8 @@ IRValue IRGenerator::loadValue(const IRValue& val) {
         std::string storageTy = val.bitfieldStorageType.empty() ? val.derefType() : val.bitfieldStorageType;
 
         std::string loaded = newTemp();
-        emit(loaded + " = load " + storageTy + ", " + storageTy + "* " + storagePtr);
+        emit(loaded + (val.isVolatile ? " = load volatile " : " = load ") + storageTy + ", " + storageTy + "* " +
+             storagePtr);
 
         std::string shifted = loaded;
         if (val.bitfieldOffset != 0) {
@@ -64,7 +65,7 @@ IRValue IRGenerator::loadValue(const IRValue& val) {
     std::string loadType = val.derefType();
 
     
-    emit(temp + " = load " + loadType + ", " + val.type + " " + val.name);
+    emit(temp + (val.isVolatile ? " = load volatile " : " = load ") + loadType + ", " + val.type + " " + val.name);
 
     IRValue result;
     result.name = temp;
@@ -5,13 +5,16 @@ namespace cc1 {
 // EN: Stores an IRValue into a pointer, handling bitfields and casts.
 // FR: Stocke une IRValue dans un pointeur, avec bitfields et casts.
 IRValue IRGenerator::storeValue(const IRValue& val, const IRValue& ptr) {
+    std::string loadOp = ptr.isVolatile ? " = load volatile " : " = load ";
+    std::string storeOp = ptr.isVolatile ? "store volatile " : "store ";
+
     if (ptr.isBitfieldRef) {
         
         std::string storagePtr = ptr.name;
         std::string storageTy = ptr.bitfieldStorageType.empty() ? ptr.derefType() : ptr.bitfieldStorageType;
 
         std::string oldV = newTemp();
-        emit(oldV + " = load " + storageTy + ", " + storageTy + "* " + storagePtr);
+        emit(oldV + loadOp + storageTy + ", " + storageTy + "* " + storagePtr);
 
         
         std::string rhsReg = val.name;
@@ -74,7 +77,7 @@ IRValue IRGenerator::storeValue(const IRValue& val, const IRValue& ptr) {
 
         std::string newV = newTemp();
         emit(newV + " = or " + storageTy + " " + cleared + ", " + shifted);
-        emit("store " + storageTy + " " + newV + ", " + storageTy + "* " + storagePtr);
+        emit(storeOp + storageTy + " " + newV + ", " + storageTy + "* " + storagePtr);
 
         return val;
     }
@@ -88,70 +91,14 @@ IRValue IRGenerator::storeValue(const IRValue& val, const IRValue& ptr) {
     std::string srcType = valueType;
 
     if (srcType != destType) {
-        // EN: Maps integer LLVM types to bit-width for casts.
-        // FR: Mappe les types entiers LLVM vers la largeur en bits.
-        auto intBits = [](const std::string& t) -> int {
-            if (t == "i1") return 1;
-            if (t == "i8") return 8;
-            if (t == "i16") return 16;
-            if (t == "i32") return 32;
-            if (t == "i64") return 64;
-            return 0;
-        };
-
-        int srcInt = intBits(srcType);
-        int dstInt = intBits(destType);
-
-        
-        if (srcInt > 0 && dstInt > 0) {
-            std::string casted = newTemp();
-            if (dstInt < srcInt) {
-                emit(casted + " = trunc " + srcType + " " + srcReg + " to " + destType);
-            } else if (dstInt > srcInt) {
-                emit(casted + " = sext " + srcType + " " + srcReg + " to " + destType);
-            } else {
-                emit(casted + " = bitcast " + srcType + " " + srcReg + " to " + destType);
-            }
-            srcReg = casted;
-            srcType = destType;
-        }
-        
-        else if (!srcType.empty() && srcType.back() == '*' && !destType.empty() && destType.back() == '*') {
-            std::string casted = newTemp();
-            emit(casted + " = bitcast " + srcType + " " + srcReg + " to " + destType);
-            srcReg = casted;
-            srcType = destType;
-        }
-        
-        else if (srcInt > 0 && !destType.empty() && destType.back() == '*') {
-            std::string casted = newTemp();
-            emit(casted + " = inttoptr " + srcType + " " + srcReg + " to " + destType);
-            srcReg = casted;
-            srcType = destType;
-        }
-        
-        else if (!srcType.empty() && srcType.back() == '*' && dstInt > 0) {
-            std::string casted = newTemp();
-            emit(casted + " = ptrtoint " + srcType + " " + srcReg + " to " + destType);
-            srcReg = casted;
-            srcType = destType;
-        }
-        
-        else if ((srcType == "float" || srcType == "double") && (destType == "float" || destType == "double")) {
-            std::string casted = newTemp();
-            if (srcType == "float" && destType == "double") {
-                emit(casted + " = fpext float " + srcReg + " to double");
-            } else if (srcType == "double" && destType == "float") {
-                emit(casted + " = fptrunc double " + srcReg + " to float");
-            } else {
-                emit(casted + " = bitcast " + srcType + " " + srcReg + " to " + destType);
-            }
-            srcReg = casted;
-            srcType = destType;
-        }
+        // EN: Converts to the pointee type (integer widths, int/float, pointers) before storing.
+        // FR: Convertit vers le type pointe (largeurs entieres, entier/flottant, pointeurs) avant le store.
+        IRValue converted = convertScalarValue(IRValue(srcReg, srcType, false, val.isConstant), destType, false);
+        srcReg = converted.name;
+        srcType = converted.type;
     }
 
-    emit("store " + srcType + " " + srcReg + ", " + ptrType + " " + ptr.name);
+    emit(storeOp + srcType + " " + srcReg + ", " + ptrType + " " + ptr.name);
 
     return val;
 }
@@ -0,0 +1,36 @@
+#include <codegen/IRGenerator.hpp>
+
+namespace cc1 {
+
+// EN: Produces the i1 truth of a loaded scalar; values widened from a comparison reuse their i1.
+// FR: Produit la verite i1 d un scalaire charge; les valeurs issues d une comparaison reutilisent leur i1.
+std::string IRGenerator::emitTruthValue(const IRValue& loaded) {
+    if (!loaded.boolName.empty()) {
+        return loaded.boolName;
+    }
+    if (loaded.type == "i1") {
+        return loaded.name;
+    }
+
+    std::string cmpReg = newTemp();
+    if (loaded.type == "float" || loaded.type == "double") {
+        emit(cmpReg + " = fcmp une " + loaded.type + " " + loaded.name + ", 0.0");
+    } else if (!loaded.type.empty() && loaded.type.back() == '*') {
+        emit(cmpReg + " = icmp ne " + loaded.type + " " + loaded.name + ", null");
+    } else {
+        emit(cmpReg + " = icmp ne " + loaded.type + " " + loaded.name + ", 0");
+    }
+    return cmpReg;
+}
+
+// EN: Widens an i1 to the C int result of a comparison or logical operator.
+// FR: Elargit un i1 vers le resultat int C d une comparaison ou d un operateur logique.
+IRValue IRGenerator::widenTruthValue(const std::string& boolReg) {
+    std::string result = newTemp();
+    emit(result + " = zext i1 " + boolReg + " to i32");
+    IRValue widened(result, "i32", false, false);
+    widened.boolName = boolReg;
+    return widened;
+}
+
+} 
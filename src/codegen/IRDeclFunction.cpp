@@ -173,11 +173,9 @@ void IRGenerator::visit(AST::FunctionDecl& node) {
     if (returnType != "void") {
         returnValuePtr_ = "%retval";
         emit(returnValuePtr_ + " = alloca " + returnType);
-        
-        
-        if (node.name == "main") {
-            emit("store " + returnType + " 0, " + returnType + "* " + returnValuePtr_);
-        }
+        // EN: Zero the slot so falling off the end (or an empty body) returns 0, not undef.
+        // FR: Met le slot a zero pour qu une fin sans return (ou un corps vide) renvoie 0.
+        emit("store " + returnType + " zeroinitializer, " + returnType + "* " + returnValuePtr_);
     }
     returnLabel_ = newLabel("return");
 
@@ -25,8 +25,9 @@ void IRGenerator::visit(AST::StructDecl& node) {
     StructLayout layout = computeStructLayout(&node);
     structLayouts_[node.name] = layout;
 
-    
-    emitGlobal(layout.llvmType);
+    // EN: Block-scope tags are not pre-collected, so register them like file-scope ones.
+    // FR: Les tags de bloc ne sont pas precollectes, on les enregistre comme ceux du fichier.
+    namedStructDefs_[node.name] = {layout.llvmType, node.declaredType.get()};
 }
 
 // EN: Registers enum constants for later codegen lookup.
@@ -45,7 +45,10 @@ void IRGenerator::visit(AST::TranslationUnit& snode) {
                 structLayouts_[structDecl->name] = layout;
 
                 
-                if (!namedStructDefs_.count(structDecl->name)) {
+                // EN: Self references ('struct N *next') may have registered an empty forward entry.
+                // FR: Les auto-references ('struct N *next') ont pu enregistrer une entree vide.
+                auto known = namedStructDefs_.find(structDecl->name);
+                if (known == namedStructDefs_.end() || !known->second.second || known->second.second->members.empty()) {
                     namedStructDefs_[structDecl->name] = {layout.llvmType, structDecl->declaredType.get()};
                 }
             }
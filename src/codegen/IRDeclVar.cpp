@@ -5,7 +5,14 @@ namespace cc1 {
 // EN: Emits IR for variable declarations, dispatching by scope/kind.
 // FR: Genere l IR pour declarations de variables selon scope/type.
 void IRGenerator::visit(AST::VarDecl& node) {
-    
+    // EN: An enum defined in the specifiers ('enum { A, B } e;', typedefs too) declares its constants.
+    // FR: Un enum defini dans les specificateurs ('enum { A, B } e;', typedefs aussi) declare ses constantes.
+    if (auto* enumType = dynamic_cast<AST::EnumType*>(stripQualifiers(node.type.get()))) {
+        for (const auto& enumerator : enumType->enumerators) {
+            enumValues_[enumerator.name] = enumerator.computedValue;
+        }
+    }
+
     if (node.storageClass == AST::StorageClass::Typedef) {
         if (node.type) {
             typedefMap_[node.name] = node.type.get();
@@ -20,13 +27,6 @@ void IRGenerator::visit(AST::VarDecl& node) {
     }
 
     if (node.name.empty()) {
-        
-        if (auto* enumType = dynamic_cast<AST::EnumType*>(stripQualifiers(node.type.get()))) {
-            
-            for (const auto& enumerator : enumType->enumerators) {
-                enumValues_[enumerator.name] = enumerator.computedValue;
-            }
-        }
         return;
     }
 
@@ -36,6 +36,7 @@ void IRGenerator::visit(AST::VarDecl& node) {
         return;
     }
 
+    completeArrayFromInitializer(node);
     std::string llvmType = typeToLLVM(node.type.get());
 
     if (inGlobalScope_) {
@@ -46,4 +47,40 @@ void IRGenerator::visit(AST::VarDecl& node) {
     emitLocalVarDecl(node, llvmType);
 }
 
+// EN: Gives `T a[] = init` the length implied by its initializer.
+// FR: Donne a `T a[] = init` la longueur impliquee par son initialiseur.
+void IRGenerator::completeArrayFromInitializer(AST::VarDecl& node) {
+    auto* arrayType = dynamic_cast<AST::ArrayType*>(stripQualifiers(node.type.get()));
+    if (!arrayType || !node.initializer || getArrayLength(arrayType) >= 0) return;
+
+    if (auto* strLit = dynamic_cast<AST::StringLiteral*>(node.initializer.get())) {
+        if (isCharArrayType(arrayType)) {
+            arrayType->size = static_cast<long long>(strLit->value.size()) + 1;
+        }
+        return;
+    }
+
+    auto* initList = dynamic_cast<AST::InitializerList*>(node.initializer.get());
+    if (!initList || initList->initializers.empty()) return;
+
+    
+    size_t count = 0;
+    size_t perElement = countFlattedMembers(arrayType->elementType.get());
+    size_t pending = 0;
+    for (const auto& init : initList->initializers) {
+        if (dynamic_cast<AST::InitializerList*>(init.get()) || perElement <= 1) {
+            if (pending > 0) {
+                count++;
+                pending = 0;
+            }
+            count++;
+        } else if (++pending == perElement) {
+            count++;
+            pending = 0;
+        }
+    }
+    if (pending > 0) count++;
+    arrayType->size = static_cast<long long>(count);
+}
+
 } 
@@ -19,8 +19,10 @@ void IRGenerator::emitGlobalVarDecl(AST::VarDecl& node, const std::string& llvmT
         }
 
         
-        emitGlobal(globalName + " = external global " + llvmType);
+        std::string externLine = globalName + " = external global " + llvmType;
+        emitGlobal(externLine);
         declaredGlobals_.insert(node.name);
+        provisionalGlobals_[node.name] = externLine;
 
         IRSymbol sym;
         sym.name = node.name;
@@ -32,7 +34,12 @@ void IRGenerator::emitGlobalVarDecl(AST::VarDecl& node, const std::string& llvmT
     }
 
     
-    if (!declaredGlobals_.empty() && declaredGlobals_.count(node.name)) {
+    // EN: A definition may follow an extern or tentative declaration of the same object.
+    // FR: Une definition peut suivre une declaration extern ou provisoire du meme objet.
+    auto provisional = provisionalGlobals_.find(node.name);
+    bool replacesProvisional = provisional != provisionalGlobals_.end() &&
+                               (node.initializer || provisional->second.find(" external ") != std::string::npos);
+    if (declaredGlobals_.count(node.name) && !replacesProvisional) {
         return;
     }
 
@@ -55,8 +62,13 @@ void IRGenerator::emitGlobalVarDecl(AST::VarDecl& node, const std::string& llvmT
             }
         } else {
             long long constVal;
+            auto* charArrayType = dynamic_cast<AST::ArrayType*>(stripQualifiers(node.type.get()));
+            auto* charArrayStr = dynamic_cast<AST::StringLiteral*>(node.initializer.get());
             if (evaluateConstantExpr(node.initializer.get(), constVal)) {
                 initValue = std::to_string(constVal);
+            } else if (charArrayStr && isCharArrayType(charArrayType)) {
+                
+                initValue = generateStringArrayInitializer(charArrayType, charArrayStr);
             } else if (auto* strLit = dynamic_cast<AST::StringLiteral*>(node.initializer.get())) {
                 
                 std::string strName = newGlobalString(strLit->value);
@@ -77,6 +89,13 @@ void IRGenerator::emitGlobalVarDecl(AST::VarDecl& node, const std::string& llvmT
 
                 
                 initValue = generateInitializerValue(node.type.get(), initList);
+            } else if (IRSymbol* fn = functionDesignatorSymbol(node.initializer.get())) {
+                // EN: A function name (or &f) initializes a function pointer with its address.
+                // FR: Un nom de fonction (ou &f) initialise un pointeur de fonction avec son adresse.
+                initValue = fn->irName;
+                if (fn->type + "*" != llvmType) {
+                    initValue = "bitcast (" + fn->type + "* " + fn->irName + " to " + llvmType + ")";
+                }
             } else {
                 initValue = getDefaultValue(node.type.get());
             }
@@ -86,10 +105,22 @@ void IRGenerator::emitGlobalVarDecl(AST::VarDecl& node, const std::string& llvmT
     }
 
     
-    std::string linkage = "dso_local global";
+    // EN: File-scope static objects are internal; const objects are immutable constants.
+    // FR: Les objets static de fichier sont internes; les objets const sont des constantes.
+    std::string linkage = node.storageClass == AST::StorageClass::Static ? "internal" : "dso_local";
+    linkage += isConstObjectType(node.type.get()) ? " constant" : " global";
 
-    emitGlobal(globalName + " = " + linkage + " " + llvmType + " " + initValue);
+    std::string globalLine = globalName + " = " + linkage + " " + llvmType + " " + initValue;
+    if (replacesProvisional) {
+        replaceGlobalLine(provisional->second, globalLine);
+        provisionalGlobals_.erase(provisional);
+    } else {
+        emitGlobal(globalLine);
+    }
     declaredGlobals_.insert(node.name);
+    if (!node.initializer) {
+        provisionalGlobals_[node.name] = globalLine;
+    }
 
     
     
@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 #include <iomanip>
 #include <sstream>
@@ -32,8 +33,14 @@ void IRGenerator::emitLocalVarDecl(AST::VarDecl& node, const std::string& llvmTy
                 }
             } else {
                 long long constVal;
+                auto* charArrayType = dynamic_cast<AST::ArrayType*>(stripQualifiers(node.type.get()));
+                auto* charArrayStr = dynamic_cast<AST::StringLiteral*>(node.initializer.get());
                 if (evaluateConstantExpr(node.initializer.get(), constVal)) {
                     initValue = std::to_string(constVal);
+                } else if (charArrayStr && isCharArrayType(charArrayType)) {
+                    initValue = generateStringArrayInitializer(charArrayType, charArrayStr);
+                } else if (auto* initList = dynamic_cast<AST::InitializerList*>(node.initializer.get())) {
+                    initValue = generateInitializerValue(node.type.get(), initList);
                 } else {
                     initValue = getDefaultValue(node.type.get());
                 }
@@ -62,7 +69,6 @@ void IRGenerator::emitLocalVarDecl(AST::VarDecl& node, const std::string& llvmTy
     
     if (node.initializer) {
         std::string valReg;
-        std::string valType = llvmType;
 
         
         if (auto* initList = dynamic_cast<AST::InitializerList*>(node.initializer.get())) {
@@ -84,72 +90,40 @@ void IRGenerator::emitLocalVarDecl(AST::VarDecl& node, const std::string& llvmTy
         }
 
         
+        auto* charArrayType = dynamic_cast<AST::ArrayType*>(stripQualifiers(node.type.get()));
+        auto* charArrayStr = dynamic_cast<AST::StringLiteral*>(node.initializer.get());
+        if (charArrayStr && isCharArrayType(charArrayType)) {
+            // EN: Copy the literal bytes into the array instead of storing its address.
+            // FR: Copie les octets du litteral dans le tableau au lieu de stocker son adresse.
+            std::string initValue = generateStringArrayInitializer(charArrayType, charArrayStr);
+            emit("store " + llvmType + " " + initValue + ", " + llvmType + "* " + ptrName);
+
+            IRSymbol sym;
+            sym.name = node.name;
+            sym.irName = ptrName;
+            sym.type = llvmType;
+            defineSymbol(node.name, sym);
+            return;
+        }
+
         node.initializer->accept(*this);
-        IRValue initVal = lastValue_;
+        IRValue rawInit = lastValue_;
 
-        valReg = initVal.name;
-        valType = initVal.type;
+        // EN: 'struct P a = b;' copies the object b designates byte for byte.
+        // FR: 'struct P a = b;' copie octet par octet l objet designe par b.
+        if (rawInit.isPointer && !rawInit.isConstant && ir_expr_call_helpers::isAggregateLLVMType(llvmType) &&
+            rawInit.derefType() == llvmType) {
+            emitAggregateCopy(IRValue(ptrName, llvmType + "*", true, false), rawInit, node.type.get());
+        } else {
+            IRValue initVal = loadValue(decayArrayValue(rawInit));
 
-        
-        if (initVal.isPointer && !initVal.isConstant) {
-            valReg = newTemp();
-            valType = initVal.derefType();
-            emit(valReg + " = load " + valType + ", " + initVal.type + " " + initVal.name);
-        }
+            // EN: Converts the value to the declared type (integer widths, floats, null pointers).
+            // FR: Convertit la valeur vers le type declare (largeurs entieres, flottants, pointeurs nuls).
+            bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.initializer);
+            valReg = convertScalarValue(initVal, llvmType, isUnsigned).name;
 
-        
-        if (valType != llvmType) {
-            std::string convertedReg = newTemp();
-            
-            if (valType == "double" && llvmType == "float") {
-                emit(convertedReg + " = fptrunc double " + valReg + " to float");
-                valReg = convertedReg;
-                valType = "float";
-            } else if (valType == "float" && llvmType == "double") {
-                emit(convertedReg + " = fpext float " + valReg + " to double");
-                valReg = convertedReg;
-                valType = "double";
-            }
-            
-            else if ((valType == "i32" || valType == "i16" || valType == "i8" || valType == "i64") &&
-                     (llvmType == "float" || llvmType == "double")) {
-                emit(convertedReg + " = sitofp " + valType + " " + valReg + " to " + llvmType);
-                valReg = convertedReg;
-                valType = llvmType;
-            }
-            
-            else if ((llvmType == "i32" || llvmType == "i16" || llvmType == "i8" || llvmType == "i64") &&
-                     (valType == "float" || valType == "double")) {
-                emit(convertedReg + " = fptosi " + valType + " " + valReg + " to " + llvmType);
-                valReg = convertedReg;
-                valType = llvmType;
-            }
-            
-            else {
-                // EN: Maps integer LLVM types to bit-width for promotions.
-                // FR: Mappe les types entiers LLVM vers la largeur en bits.
-                auto getIntSize = [](const std::string& t) -> int {
-                    if (t == "i8") return 8;
-                    if (t == "i16") return 16;
-                    if (t == "i32") return 32;
-                    if (t == "i64") return 64;
-                    return 0;
-                };
-                int srcSize = getIntSize(valType);
-                int dstSize = getIntSize(llvmType);
-                if (srcSize > 0 && dstSize > 0) {
-                    if (srcSize < dstSize) {
-                        emit(convertedReg + " = sext " + valType + " " + valReg + " to " + llvmType);
-                    } else {
-                        emit(convertedReg + " = trunc " + valType + " " + valReg + " to " + llvmType);
-                    }
-                    valReg = convertedReg;
-                    valType = llvmType;
-                }
-            }
+            emit("store " + llvmType + " " + valReg + ", " + llvmType + "* " + ptrName);
         }
-
-        emit("store " + llvmType + " " + valReg + ", " + llvmType + "* " + ptrName);
     }
 
     
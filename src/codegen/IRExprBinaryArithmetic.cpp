@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -44,12 +45,19 @@ bool IRGenerator::emitBinaryArithmetic(AST::BinaryExpr& node,
             
             if (!lhsType.empty() && lhsType.back() == '*') {
                 if (!rhsType.empty() && rhsType.back() == '*') {
+                    // EN: Pointer difference counts elements: byte distance divided by the element size.
+                    // FR: La difference de pointeurs compte des elements: distance en octets / taille.
+                    std::string idxType = is64bit_ ? "i64" : "i32";
+                    std::string elemType = lhsType.substr(0, lhsType.size() - 1);
                     std::string lhsInt = newTemp();
                     std::string rhsInt = newTemp();
-                    emit(lhsInt + " = ptrtoint " + lhsType + " " + lhsReg + " to i32");
-                    emit(rhsInt + " = ptrtoint " + rhsType + " " + rhsReg + " to i32");
-                    emit(outResult + " = sub i32 " + lhsInt + ", " + rhsInt);
-                    outResultType = "i32";
+                    std::string byteDiff = newTemp();
+                    emit(lhsInt + " = ptrtoint " + lhsType + " " + lhsReg + " to " + idxType);
+                    emit(rhsInt + " = ptrtoint " + rhsType + " " + rhsReg + " to " + idxType);
+                    emit(byteDiff + " = sub " + idxType + " " + lhsInt + ", " + rhsInt);
+                    emit(outResult + " = sdiv exact " + idxType + " " + byteDiff + ", ptrtoint (" + lhsType +
+                         " getelementptr (" + elemType + ", " + lhsType + " null, i32 1) to " + idxType + ")");
+                    outResultType = idxType;
                 } else {
                     std::string elemType = lhsType.substr(0, lhsType.size() - 1);
                     std::string negIdx = newTemp();
@@ -90,7 +98,10 @@ bool IRGenerator::emitBinaryArithmetic(AST::BinaryExpr& node,
             if (isFloatType(lhsType)) {
                 emit(outResult + " = frem " + lhsType + " " + lhsReg + ", " + rhsReg);
             } else {
-                emit(outResult + " = srem " + lhsType + " " + lhsReg + ", " + rhsReg);
+                // EN: The remainder follows the signedness of the converted operands: srem truncates toward zero.
+                // FR: Le reste suit la signature des operandes convertis: srem tronque vers zero.
+                bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(node);
+                emit(outResult + (isUnsigned ? " = urem " : " = srem ") + lhsType + " " + lhsReg + ", " + rhsReg);
             }
             outResultType = lhsType;
             return true;
@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -9,12 +10,29 @@ bool IRGenerator::emitBinaryAssign(AST::BinaryExpr& node) {
 
     
     node.right->accept(*this);
-    IRValue rhsVal = loadValue(lastValue_);
+    IRValue rhsRaw = lastValue_;
 
     
     node.left->accept(*this);
     IRValue lhsVal = lastValue_;
 
+    // EN: Struct assignment copies the whole object, nested arrays and structs included.
+    // FR: L assignation de struct copie l objet entier, tableaux et structs imbriques compris.
+    if (rhsRaw.isPointer && !rhsRaw.isConstant && node.left->resolvedType &&
+        ir_expr_call_helpers::isAggregateLLVMType(lhsVal.derefType()) && rhsRaw.derefType() == lhsVal.derefType()) {
+        emitAggregateCopy(lhsVal, rhsRaw, node.left->resolvedType.get());
+        lastValue_ = lhsVal;
+        return true;
+    }
+    IRValue rhsVal = loadValue(decayArrayValue(rhsRaw));
+
+    // EN: The stored value takes the type of the object designated by the left operand.
+    // FR: La valeur stockee prend le type de l objet designe par l operande gauche.
+    if (!lhsVal.isBitfieldRef) {
+        bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.right);
+        rhsVal = convertScalarValue(rhsVal, lhsVal.derefType(), isUnsigned);
+    }
+
     
     storeValue(rhsVal, lhsVal);
 
@@ -25,8 +25,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
                 }
             }
             emit(cmp + " = icmp eq " + lhsType + " " + lhsReg + ", " + rhsValue);
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -42,8 +42,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
                 }
             }
             emit(cmp + " = icmp ne " + lhsType + " " + lhsReg + ", " + rhsValue);
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -55,8 +55,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
             } else {
                 emit(cmp + " = icmp slt " + lhsType + " " + lhsReg + ", " + rhsReg);
             }
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -67,8 +67,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
             } else {
                 emit(cmp + " = icmp sle " + lhsType + " " + lhsReg + ", " + rhsReg);
             }
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -79,8 +79,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
             } else {
                 emit(cmp + " = icmp sgt " + lhsType + " " + lhsReg + ", " + rhsReg);
             }
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -91,8 +91,8 @@ bool IRGenerator::emitBinaryComparison(AST::BinaryExpr& node,
             } else {
                 emit(cmp + " = icmp sge " + lhsType + " " + lhsReg + ", " + rhsReg);
             }
-            outResult = newTemp();
-            emit(outResult + " = zext i1 " + cmp + " to i32");
+            lastValue_ = widenTruthValue(cmp);
+            outResult = lastValue_.name;
             outResultType = "i32";
             return true;
         }
@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -55,7 +56,14 @@ bool IRGenerator::emitBinaryCompoundAssign(AST::BinaryExpr& node) {
         case AST::BinaryOp::SubAssign: opName = "sub"; break;
         case AST::BinaryOp::MulAssign: opName = "mul"; break;
         case AST::BinaryOp::DivAssign: opName = "sdiv"; break;
-        case AST::BinaryOp::ModAssign: opName = "srem"; break;
+        case AST::BinaryOp::ModAssign:
+            // EN: An unsigned operand makes the remainder unsigned after the usual conversions.
+            // FR: Un operande non signe rend le reste non signe apres les conversions usuelles.
+            opName = (ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.left) ||
+                      ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.right))
+                         ? "urem"
+                         : "srem";
+            break;
         case AST::BinaryOp::AndAssign: opName = "and"; break;
         case AST::BinaryOp::OrAssign: opName = "or"; break;
         case AST::BinaryOp::XorAssign: opName = "xor"; break;
@@ -14,11 +14,7 @@ bool IRGenerator::emitBinaryLogicalShortCircuit(AST::BinaryExpr& node) {
 
     
     node.left->accept(*this);
-    IRValue lhsLoaded = loadValue(lastValue_);
-    std::string lhsReg = lhsLoaded.name;
-
-    std::string cmpReg = newTemp();
-    emit(cmpReg + " = icmp ne " + lhsLoaded.type + " " + lhsReg + ", 0");
+    std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
 
     std::string lhsEvalLabel = newLabel("land.lhs.eval");
     emit("br label %" + lhsEvalLabel);
@@ -33,11 +29,7 @@ bool IRGenerator::emitBinaryLogicalShortCircuit(AST::BinaryExpr& node) {
     
     emitLabel(rhsLabel);
     node.right->accept(*this);
-    IRValue rhsLoaded = loadValue(lastValue_);
-    std::string rhsReg = rhsLoaded.name;
-
-    std::string rhsCmp = newTemp();
-    emit(rhsCmp + " = icmp ne " + rhsLoaded.type + " " + rhsReg + ", 0");
+    std::string rhsCmp = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
 
     
     std::string rhsFromLabel = newLabel("land.rhs.from");
@@ -55,10 +47,7 @@ bool IRGenerator::emitBinaryLogicalShortCircuit(AST::BinaryExpr& node) {
     }
 
     
-    std::string extResult = newTemp();
-    emit(extResult + " = zext i1 " + result + " to i32");
-
-    lastValue_ = IRValue(extResult, "i32", false, false);
+    lastValue_ = widenTruthValue(result);
     return true;
 }
 
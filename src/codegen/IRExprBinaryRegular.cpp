@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -7,10 +8,10 @@ namespace cc1 {
 void IRGenerator::emitBinaryRegular(AST::BinaryExpr& node) {
     
     node.left->accept(*this);
-    IRValue lhsVal = loadValue(lastValue_);
+    IRValue lhsVal = loadValue(decayArrayValue(lastValue_));
 
     node.right->accept(*this);
-    IRValue rhsVal = loadValue(lastValue_);
+    IRValue rhsVal = loadValue(decayArrayValue(lastValue_));
 
     std::string lhsReg = lhsVal.name;
     std::string lhsType = lhsVal.type;
@@ -47,6 +48,39 @@ void IRGenerator::emitBinaryRegular(AST::BinaryExpr& node) {
         }
     }
 
+    // EN: Brings pointer comparison operands to one pointer type ((void*)0, NULL, mixed pointees).
+    // FR: Ramene les operandes de comparaison pointeur a un meme type ((void*)0, NULL, pointes differents).
+    bool isComparison = node.op == AST::BinaryOp::Equal || node.op == AST::BinaryOp::NotEqual ||
+                        node.op == AST::BinaryOp::Less || node.op == AST::BinaryOp::LessEqual ||
+                        node.op == AST::BinaryOp::Greater || node.op == AST::BinaryOp::GreaterEqual;
+    bool lhsIsPtr = !lhsType.empty() && lhsType.back() == '*';
+    bool rhsIsPtr = !rhsType.empty() && rhsType.back() == '*';
+    if (isComparison && lhsType != rhsType && (lhsIsPtr || rhsIsPtr)) {
+        if (lhsIsPtr) {
+            IRValue converted = convertScalarValue(IRValue(rhsReg, rhsType, false, rhsVal.isConstant), lhsType, false);
+            rhsReg = converted.name;
+            rhsType = lhsType;
+        } else {
+            IRValue converted = convertScalarValue(IRValue(lhsReg, lhsType, false, lhsVal.isConstant), rhsType, false);
+            lhsReg = converted.name;
+            lhsType = rhsType;
+        }
+    }
+
+    // EN: Pointer offsets are signed and index-sized so p + -2 and p[i] GEP correctly on every target.
+    // FR: Les deplacements de pointeur sont signes et a la taille d index pour que p + -2 et p[i] marchent.
+    bool isOffset = node.op == AST::BinaryOp::Add || node.op == AST::BinaryOp::Sub;
+    std::string idxType = is64bit_ ? "i64" : "i32";
+    if (isOffset && lhsIsPtr && !rhsIsPtr && rhsType != idxType) {
+        bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.right);
+        rhsReg = convertScalarValue(IRValue(rhsReg, rhsType, false, rhsVal.isConstant), idxType, isUnsigned).name;
+        rhsType = idxType;
+    } else if (node.op == AST::BinaryOp::Add && rhsIsPtr && !lhsIsPtr && lhsType != idxType) {
+        bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.left);
+        lhsReg = convertScalarValue(IRValue(lhsReg, lhsType, false, lhsVal.isConstant), idxType, isUnsigned).name;
+        lhsType = idxType;
+    }
+
     std::string result;
     std::string resultType = lhsType;
 
@@ -56,7 +90,6 @@ void IRGenerator::emitBinaryRegular(AST::BinaryExpr& node) {
     }
 
     if (emitBinaryComparison(node, rhsVal, lhsReg, lhsType, rhsReg, result, resultType)) {
-        lastValue_ = IRValue(result, resultType, false, false);
         return;
     }
 
@@ -14,6 +14,9 @@ void IRGenerator::visit(AST::CallExpr& node) {
     std::string funcType;      // May be "ret (args)" or "ret (args)*" for function pointers.
     std::string calleeIdName;
     if (auto* id = dynamic_cast<AST::Identifier*>(node.callee.get())) {
+        if (emitVaBuiltinCall(node, id->name)) {
+            return;
+        }
         calleeIdName = id->name;
         funcName = "@" + id->name;
 
@@ -177,6 +180,19 @@ void IRGenerator::visit(AST::CallExpr& node) {
                     emit(casted + " = fptrunc double " + argName + " to float");
                     argType = "float";
                     argName = casted;
+                } else if (isAggregateLLVMType(expected) && argType == expected + "*") {
+                    // EN: struct/union parameters are passed by value, so load the object.
+                    // FR: Les parametres struct/union passent par valeur, on charge l objet.
+                    IRValue loaded = loadValue(args[i]);
+                    argType = loaded.type;
+                    argName = loaded.name;
+                } else if (!expected.empty() && expected.back() == '*' &&
+                           ((!argType.empty() && argType.back() == '*') || fromBits > 0)) {
+                    // EN: Null pointer constants and other pointers convert to the parameter pointer type.
+                    // FR: Les constantes pointeur nul et autres pointeurs sont convertis vers le type du parametre.
+                    IRValue converted = convertScalarValue(args[i], expected, false);
+                    argType = converted.type;
+                    argName = converted.name;
                 }
             }
         } else if (applyDefaultArgPromotions) {
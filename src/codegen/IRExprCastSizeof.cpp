@@ -86,7 +86,8 @@ void IRGenerator::visit(AST::SizeofExpr& node) {
     int size = 0;
 
     if (node.operand) {
-        
+        // EN: The operand is never evaluated: sizeof(f()) uses f's return type and emits no call.
+        // FR: L operande n est jamais evalue: sizeof(f()) utilise le type de retour de f sans appel.
         if (node.operand->resolvedType) {
             size = getTypeSize(node.operand->resolvedType.get());
         }
@@ -95,7 +96,9 @@ void IRGenerator::visit(AST::SizeofExpr& node) {
         size = getTypeSize(node.targetType.get());
     }
 
-    lastValue_ = IRValue(std::to_string(size), "i32", false, true);
+    // EN: sizeof yields a size_t (unsigned long), so the constant already has the target width.
+    // FR: sizeof produit un size_t (unsigned long), la constante a donc deja la largeur cible.
+    lastValue_ = IRValue(std::to_string(size), is64bit_ ? "i64" : "i32", false, true);
 }
 
 } 
@@ -37,6 +37,7 @@ void IRGenerator::visit(AST::Identifier& node) {
     } else {
         
         lastValue_ = IRValue(sym->irName, sym->type + "*", true, false);
+        lastValue_.isVolatile = isVolatileObjectType(node.resolvedType.get());
     }
 }
 
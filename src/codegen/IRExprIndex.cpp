@@ -1,15 +1,34 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
+
+#include <utility>
 
 namespace cc1 {
 
 // EN: Emits IR for array/pointer indexing with GEP.
 // FR: Genere l IR pour l indexation tableau/pointeur via GEP.
 void IRGenerator::visit(AST::IndexExpr& node) {
-    node.array->accept(*this);
+    // EN: Detects pointer/array operands so i[p] is lowered like p[i].
+    // FR: Detecte les operandes pointeur/tableau pour traiter i[p] comme p[i].
+    auto isAddressType = [this](AST::Expression* expr) -> bool {
+        AST::Type* type = expr->resolvedType ? stripQualifiers(expr->resolvedType.get()) : nullptr;
+        if (auto* td = dynamic_cast<AST::TypedefType*>(type)) {
+            type = stripQualifiers(resolveTypedef(td));
+        }
+        return dynamic_cast<AST::PointerType*>(type) || dynamic_cast<AST::ArrayType*>(type);
+    };
+
+    AST::Expression* base = node.array.get();
+    AST::Expression* offset = node.index.get();
+    if (!isAddressType(base) && isAddressType(offset)) {
+        std::swap(base, offset);
+    }
+
+    base->accept(*this);
     IRValue arrVal = lastValue_;
 
     
-    node.index->accept(*this);
+    offset->accept(*this);
     IRValue idxVal = loadValue(lastValue_);
 
     
@@ -50,9 +69,10 @@ void IRGenerator::visit(AST::IndexExpr& node) {
     std::string idxType = is64bit_ ? "i64" : "i32";
     std::string idxReg = idxVal.name;
     if (idxType != idxVal.type) {
-        std::string castIdx = newTemp();
-        emit(castIdx + " = sext " + idxVal.type + " " + idxVal.name + " to " + idxType);
-        idxReg = castIdx;
+        // EN: Signed indices sign-extend so a[-1] addresses the previous element.
+        // FR: Les index signes sont etendus en signe pour que a[-1] vise l element precedent.
+        bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*offset);
+        idxReg = convertScalarValue(idxVal, idxType, isUnsigned).name;
     }
 
     if (!arrVal.derefType().empty() && arrVal.derefType().front() == '[') {
@@ -9,6 +9,20 @@ void IRGenerator::visit(AST::MemberExpr& node) {
     node.object->accept(*this);
     IRValue baseVal = lastValue_;
 
+    // EN: `p->m` reads the pointer first; the pointee's qualifiers decide volatility.
+    // FR: `p->m` lit d abord le pointeur; les qualifiers du pointe decident de la volatilite.
+    if (node.isArrow) {
+        baseVal = loadValue(decayArrayValue(baseVal));
+        baseVal.isPointer = false;
+        baseVal.isVolatile = false;
+        AST::Type* objectType = stripQualifiers(node.object->resolvedType.get());
+        if (auto* ptrType = dynamic_cast<AST::PointerType*>(objectType)) {
+            baseVal.isVolatile = isVolatileObjectType(ptrType->pointee.get());
+        } else if (auto* arrType = dynamic_cast<AST::ArrayType*>(objectType)) {
+            baseVal.isVolatile = isVolatileObjectType(arrType->elementType.get());
+        }
+    }
+
     
     bool isPtr = baseVal.type.size() > 1 && baseVal.type.back() == '*';
 
@@ -82,6 +96,7 @@ void IRGenerator::visit(AST::MemberExpr& node) {
     }
 
     IRValue memberVal(finalMemberPtr, memberLLVMType + "*", true, false);
+    memberVal.isVolatile = baseVal.isVolatile || (layout && layout->volatileMembers.count(node.member));
 
     
     if (layout && layout->bitfieldWidths.count(node.member)) {
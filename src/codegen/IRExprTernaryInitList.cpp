@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -7,20 +8,30 @@ namespace cc1 {
 void IRGenerator::visit(AST::TernaryExpr& node) {
     
     node.condition->accept(*this);
-    IRValue condVal = loadValue(lastValue_);
+    std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
+
+    // EN: Both arms are converted to the semantic result type before merging.
+    // FR: Les deux branches sont converties vers le type resultat semantique avant fusion.
+    std::string resultType;
+    if (node.resolvedType) {
+        resultType = typeToLLVM(node.resolvedType.get());
+    }
 
     std::string thenLabel = newLabel("ternary.then");
     std::string elseLabel = newLabel("ternary.else");
     std::string endLabel = newLabel("ternary.end");
 
-    std::string cmpReg = newTemp();
-    emit(cmpReg + " = icmp ne " + condVal.type + " " + condVal.name + ", 0");
     emit("br i1 " + cmpReg + ", label %" + thenLabel + ", label %" + elseLabel);
 
     
     emitLabel(thenLabel);
     node.thenExpr->accept(*this);
-    IRValue thenVal = loadValue(lastValue_);
+    IRValue thenVal = loadValue(decayArrayValue(lastValue_));
+    if (resultType.empty()) {
+        resultType = thenVal.type;
+    }
+    thenVal = convertScalarValue(thenVal, resultType,
+                                 ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.thenExpr));
     std::string thenFrom = newLabel("ternary.then.from");
     emit("br label %" + thenFrom);
     emitLabel(thenFrom);
@@ -29,7 +40,9 @@ void IRGenerator::visit(AST::TernaryExpr& node) {
     
     emitLabel(elseLabel);
     node.elseExpr->accept(*this);
-    IRValue elseVal = loadValue(lastValue_);
+    IRValue elseVal = loadValue(decayArrayValue(lastValue_));
+    elseVal = convertScalarValue(elseVal, resultType,
+                                 ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.elseExpr));
     std::string elseFrom = newLabel("ternary.else.from");
     emit("br label %" + elseFrom);
     emitLabel(elseFrom);
@@ -39,7 +52,11 @@ void IRGenerator::visit(AST::TernaryExpr& node) {
     emitLabel(endLabel);
 
     
-    std::string resultType = thenVal.type;
+    if (resultType == "void") {
+        lastValue_ = IRValue("0", "i32", false, true);
+        return;
+    }
+
     std::string phiReg = newTemp();
     emit(phiReg + " = phi " + resultType + " [ " + thenVal.name + ", %" + thenFrom + " ], [ " + elseVal.name + ", %" + elseFrom + " ]");
 
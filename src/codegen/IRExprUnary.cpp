@@ -15,7 +15,7 @@ void IRGenerator::visit(AST::UnaryExpr& node) {
         
         std::string oldVal = newTemp();
         std::string valType = ptr.derefType();
-        emit(oldVal + " = load " + valType + ", " + ptr.type + " " + ptr.name);
+        emit(oldVal + (ptr.isVolatile ? " = load volatile " : " = load ") + valType + ", " + ptr.type + " " + ptr.name);
 
         
         std::string newVal = newTemp();
@@ -38,7 +38,7 @@ void IRGenerator::visit(AST::UnaryExpr& node) {
         }
 
         
-        emit("store " + valType + " " + newVal + ", " + ptr.type + " " + ptr.name);
+        emit((ptr.isVolatile ? "store volatile " : "store ") + valType + " " + newVal + ", " + ptr.type + " " + ptr.name);
 
         
         if (node.op == AST::UnaryOp::PreIncrement || node.op == AST::UnaryOp::PreDecrement) {
@@ -51,6 +51,13 @@ void IRGenerator::visit(AST::UnaryExpr& node) {
 
     
     node.operand->accept(*this);
+    if (node.op == AST::UnaryOp::AddressOf) {
+        // EN: Taking an address never reads the object (matters for volatile operands).
+        // FR: Prendre une adresse ne lit jamais l objet (important pour les operandes volatiles).
+        lastValue_.isPointer = false;
+        lastValue_.isVolatile = false;
+        return;
+    }
     IRValue operandVal = loadValue(lastValue_);
 
     std::string operandReg = operandVal.name;
@@ -68,29 +75,16 @@ void IRGenerator::visit(AST::UnaryExpr& node) {
             }
             break;
         case AST::UnaryOp::LogicalNot: {
+            std::string truth = emitTruthValue(operandVal);
             std::string cmp = newTemp();
-            if (!operandType.empty() && operandType.back() == '*') {
-                emit(cmp + " = icmp eq " + operandType + " " + operandReg + ", null");
-            } else {
-                emit(cmp + " = icmp eq " + operandType + " " + operandReg + ", 0");
-            }
-            result = newTemp();
-            emit(result + " = zext i1 " + cmp + " to i32");
-            operandType = "i32";
-            break;
+            emit(cmp + " = xor i1 " + truth + ", true");
+            lastValue_ = widenTruthValue(cmp);
+            return;
         }
         case AST::UnaryOp::BitwiseNot:
             result = newTemp();
             emit(result + " = xor " + operandType + " " + operandReg + ", -1");
             break;
-        case AST::UnaryOp::AddressOf:
-            
-            {
-                IRValue v = lastValue_;
-                v.isPointer = false;
-                lastValue_ = v;
-            }
-            return;
         case AST::UnaryOp::Dereference: {
             // Dereferencing a pointer makes it addressable (isPointer = true).
             // However, function pointers are special: dereferencing a function pointer
@@ -109,6 +103,7 @@ void IRGenerator::visit(AST::UnaryExpr& node) {
                 } else {
                     // Normal pointer dereference - mark as addressable
                     lastValue_ = IRValue(operandReg, operandType, true, false);
+                    lastValue_.isVolatile = isVolatileObjectType(node.resolvedType.get());
                 }
             } else {
                 lastValue_ = operandVal;
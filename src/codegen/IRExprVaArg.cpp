@@ -0,0 +1,65 @@
+#include <codegen/IRGenerator.hpp>
+
+namespace cc1 {
+
+// EN: Returns the va_list object address: the decayed __va_list_tag array on x86-64, the char* slot on i386.
+// FR: Renvoie l adresse du va_list: le tableau __va_list_tag decroit en x86-64, la case char* en i386.
+IRValue IRGenerator::emitVaListAddress(AST::Expression* list) {
+    list->accept(*this);
+    if (!is64bit_) {
+        return lastValue_;
+    }
+    // EN: A va_list parameter has already decayed to a pointer and must be loaded.
+    // FR: Un parametre va_list est deja decroit en pointeur et doit etre charge.
+    return loadValue(decayArrayValue(lastValue_));
+}
+
+// EN: Emits va_arg for the requested type, typedefs resolved to their LLVM type.
+// FR: Genere va_arg pour le type demande, typedefs resolus vers leur type LLVM.
+void IRGenerator::visit(AST::VaArgExpr& node) {
+    IRValue ap = emitVaListAddress(node.list.get());
+    std::string argType = typeToLLVM(node.targetType.get());
+
+    std::string result = newTemp();
+    emit(result + " = va_arg " + ap.type + " " + ap.name + ", " + argType);
+    lastValue_ = IRValue(result, argType, false, false);
+}
+
+// EN: Lowers __builtin_va_start/end/copy to the llvm.va_* intrinsics; false for other callees.
+// FR: Abaisse __builtin_va_start/end/copy vers les intrinseques llvm.va_*; false sinon.
+bool IRGenerator::emitVaBuiltinCall(AST::CallExpr& node, const std::string& name) {
+    std::string intrinsic;
+    size_t listCount = 1;
+    if (name == "__builtin_va_start") {
+        intrinsic = "llvm.va_start";
+    } else if (name == "__builtin_va_end") {
+        intrinsic = "llvm.va_end";
+    } else if (name == "__builtin_va_copy") {
+        intrinsic = "llvm.va_copy";
+        listCount = 2;
+    } else {
+        return false;
+    }
+
+    // EN: va_start's second operand (the last named parameter) carries no value in IR.
+    // FR: Le second operande de va_start (dernier parametre nomme) n a pas de valeur en IR.
+    std::string args;
+    std::string params;
+    for (size_t i = 0; i < listCount && i < node.arguments.size(); ++i) {
+        IRValue ap = emitVaListAddress(node.arguments[i].get());
+        std::string raw = ap.name;
+        if (ap.type != "i8*") {
+            raw = newTemp();
+            emit(raw + " = bitcast " + ap.type + " " + ap.name + " to i8*");
+        }
+        args += (i ? ", i8* " : "i8* ") + raw;
+        params += i ? ", i8*" : "i8*";
+    }
+
+    emit("call void @" + intrinsic + "(" + args + ")");
+    functionDeclarations_[intrinsic] = "declare void @" + intrinsic + "(" + params + ")\n";
+    lastValue_ = IRValue("0", "i32", false, true);
+    return true;
+}
+
+}
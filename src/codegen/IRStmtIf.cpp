@@ -12,21 +12,7 @@ void IRGenerator::visit(AST::IfStmt& node) {
 
     
     node.condition->accept(*this);
-    IRValue condVal = lastValue_;
-
-    
-    IRValue condLoaded = loadValue(condVal);
-    std::string condReg = condLoaded.name;
-    std::string condType = condLoaded.type;
-
-    
-    
-    std::string cmpReg = newTemp();
-    if (condType.back() == '*') {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", null");
-    } else {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", 0");
-    }
+    std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
 
     
     if (node.elseBranch) {
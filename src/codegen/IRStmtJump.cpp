@@ -1,4 +1,5 @@
 #include <codegen/IRGenerator.hpp>
+#include <codegen/IRExprCallHelpers.hpp>
 
 namespace cc1 {
 
@@ -39,37 +40,20 @@ void IRGenerator::visit(AST::ReturnStmt& node) {
         node.value->accept(*this);
         IRValue retVal = lastValue_;
 
-        IRValue retLoaded = loadValue(retVal);
-        std::string retReg = retLoaded.name;
-        std::string retType = retLoaded.type;
-
-        
-        if (retType != currentFunctionReturnType_) {
-            std::string converted = newTemp();
-            
-            // EN: Maps integer LLVM types to bit-width for promotions.
-            // FR: Mappe les types entiers LLVM vers la largeur en bits.
-            auto getIntSize = [](const std::string& t) -> int {
-                if (t == "i8") return 8;
-                if (t == "i16") return 16;
-                if (t == "i32") return 32;
-                if (t == "i64") return 64;
-                return 0;
-            };
-            int srcSize = getIntSize(retType);
-            int dstSize = getIntSize(currentFunctionReturnType_);
-
-            if (srcSize > 0 && dstSize > 0) {
-                if (srcSize > dstSize) {
-                    emit(converted + " = trunc " + retType + " " + retReg + " to " + currentFunctionReturnType_);
-                } else {
-                    emit(converted + " = sext " + retType + " " + retReg + " to " + currentFunctionReturnType_);
-                }
-                retReg = converted;
-            }
+        // EN: Returning a struct object copies all of it into the return slot.
+        // FR: Renvoyer un objet struct le copie entierement dans la case de retour.
+        if (retVal.isPointer && !retVal.isConstant && retVal.derefType() == currentFunctionReturnType_ &&
+            ir_expr_call_helpers::isAggregateLLVMType(currentFunctionReturnType_)) {
+            emitAggregateCopy(IRValue(returnValuePtr_, currentFunctionReturnType_ + "*", true, false), retVal,
+                              currentFunction_->returnType.get());
+        } else {
+            IRValue retLoaded = loadValue(decayArrayValue(retVal));
+            bool isUnsigned = ir_expr_call_helpers::isUnsignedIntegralResolvedType(*node.value);
+            std::string retReg = convertScalarValue(retLoaded, currentFunctionReturnType_, isUnsigned).name;
+
+            emit("store " + currentFunctionReturnType_ + " " + retReg + ", " + currentFunctionReturnType_ + "* " +
+                 returnValuePtr_);
         }
-
-        emit("store " + currentFunctionReturnType_ + " " + retReg + ", " + currentFunctionReturnType_ + "* " + returnValuePtr_);
     }
 
     emit("br label %" + returnLabel_);
@@ -20,19 +20,7 @@ void IRGenerator::visit(AST::WhileStmt& node) {
     
     emitLabel(condLabel);
     node.condition->accept(*this);
-    IRValue condVal = lastValue_;
-
-    
-    IRValue condLoaded = loadValue(condVal);
-    std::string condReg = condLoaded.name;
-    std::string condType = condLoaded.type;
-
-    std::string cmpReg = newTemp();
-    if (condType.back() == '*') {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", null");
-    } else {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", 0");
-    }
+    std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
     emit("br i1 " + cmpReg + ", label %" + bodyLabel + ", label %" + endLabel);
 
     
@@ -71,19 +59,7 @@ void IRGenerator::visit(AST::DoWhileStmt& node) {
     
     emitLabel(condLabel);
     node.condition->accept(*this);
-    IRValue condVal = lastValue_;
-
-    
-    IRValue condLoaded = loadValue(condVal);
-    std::string condReg = condLoaded.name;
-    std::string condType = condLoaded.type;
-
-    std::string cmpReg = newTemp();
-    if (condType.back() == '*') {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", null");
-    } else {
-        emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", 0");
-    }
+    std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
     emit("br i1 " + cmpReg + ", label %" + bodyLabel + ", label %" + endLabel);
 
     
@@ -119,19 +95,7 @@ void IRGenerator::visit(AST::ForStmt& node) {
     emitLabel(condLabel);
     if (node.condition) {
         node.condition->accept(*this);
-        IRValue condVal = lastValue_;
-
-        
-        IRValue condLoaded = loadValue(condVal);
-        std::string condReg = condLoaded.name;
-        std::string condType = condLoaded.type;
-
-        std::string cmpReg = newTemp();
-        if (condType.back() == '*') {
-            emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", null");
-        } else {
-            emit(cmpReg + " = icmp ne " + condType + " " + condReg + ", 0");
-        }
+        std::string cmpReg = emitTruthValue(loadValue(decayArrayValue(lastValue_)));
         emit("br i1 " + cmpReg + ", label %" + bodyLabel + ", label %" + endLabel);
     } else {
         emit("br label %" + bodyLabel);
@@ -29,29 +29,10 @@ std::string IRGenerator::getDefaultValue(AST::Type* type) {
         return "zeroinitializer";
     }
 
-    if (auto* structType = dynamic_cast<AST::StructType*>(type)) {
-        // For unions, just use zeroinitializer (they're now byte arrays)
-        if (structType->isUnion) {
-            return "zeroinitializer";
-        }
-        
-        if (structType->members.empty()) {
-            return "zeroinitializer";
-        }
-        
-        std::string result = "{ ";
-        bool first = true;
-
-        for (size_t i = 0; i < structType->members.size(); ++i) {
-            if (!first) result += ", ";
-            first = false;
-            const auto& member = structType->members[i];
-            std::string memberType = typeToLLVM(stripQualifiers(member.type.get()));
-            result += memberType + " " + getDefaultValue(stripQualifiers(member.type.get()));
-        }
-        result += " }";
-        return result;
-
+    if (dynamic_cast<AST::StructType*>(type)) {
+        // EN: Padding fields and union storage make zeroinitializer the only layout-safe form.
+        // FR: Les champs de padding et le stockage des unions rendent zeroinitializer seul sur.
+        return "zeroinitializer";
     }
 
     return "0";
@@ -21,8 +21,9 @@ size_t IRGenerator::countFlattedMembers(AST::Type* type) {
     
     
     if (auto* arr = dynamic_cast<AST::ArrayType*>(type)) {
-        if (arr->size > 0) {
-            return arr->size;
+        long long length = getArrayLength(arr);
+        if (length > 0) {
+            return static_cast<size_t>(length);
         }
         return 1;  
     }
@@ -30,6 +31,7 @@ size_t IRGenerator::countFlattedMembers(AST::Type* type) {
     
     
     if (auto* st = dynamic_cast<AST::StructType*>(type)) {
+        st = resolveStructDefinition(st);
         if (st->isUnion) {
             return 1;  
         }
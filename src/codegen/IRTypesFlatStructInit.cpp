@@ -7,231 +7,151 @@ namespace cc1 {
 std::string IRGenerator::generateStructInitializerFromFlatHelper(AST::StructType* st, AST::InitializerList* flatList,
                                                                 size_t& idx) {
     if (!st || !flatList) return "zeroinitializer";
+    st = resolveStructDefinition(st);
     if (st->members.empty()) return "zeroinitializer";
 
-    // Get the computed layout for this struct to handle union members correctly
+    // Get the computed layout for this struct to handle union members and padding correctly
     StructLayout layout = computeStructLayout(st);
 
     std::string result = "{ ";
     bool first = true;
+    int emittedFields = 0;
 
-    size_t i = 0;
-    while (i < st->members.size()) {
+    // EN: Zero-fills layout fields (padding, skipped units) up to fieldIndex.
+    // FR: Remplit de zeros les champs du layout (padding, unites sautees) jusqu a fieldIndex.
+    auto padTo = [&](int fieldIndex) {
+        while (emittedFields < fieldIndex) {
+            if (!first) result += ", ";
+            first = false;
+            result += extractFieldTypeFromInlineStruct(layout.llvmType, emittedFields) + " zeroinitializer";
+            emittedFields++;
+        }
+    };
+
+    // EN: Starts a new field entry at the member's layout index.
+    // FR: Demarre une entree de champ a l index layout du membre.
+    auto beginField = [&](const std::string& memberName) {
+        auto it = layout.memberIndices.find(memberName);
+        if (it != layout.memberIndices.end()) {
+            padTo(it->second);
+        }
         if (!first) result += ", ";
         first = false;
+        emittedFields++;
+    };
 
+    size_t i = 0;
+    while (i < st->members.size()) {
         const auto& member = st->members[i];
         AST::Type* memberType = stripQualifiers(member.type.get());
         std::string memberTypeStr = typeToLLVM(memberType);
-        
-        // For union members in structs with computed layouts, use the byte array type from the layout
-        if (auto* structMemberType = dynamic_cast<AST::StructType*>(memberType)) {
-            if (structMemberType->isUnion) {
-                // Use the byte array type from the layout instead of the named struct type
-                memberTypeStr = "i8";
-            }
+        auto typeIt = layout.memberTypes.find(member.name);
+        if (typeIt != layout.memberTypes.end()) {
+            memberTypeStr = typeIt->second;
         }
 
-        
         if (member.isBitfield()) {
-            
-            int totalBits = member.bitWidth;
-            size_t j = i + 1;
+            // EN: Pack consecutive bitfields into their storage units from the layout.
+            // FR: Regroupe les bitfields consecutifs dans leurs unites de stockage du layout.
+            std::map<int, long long> unitValues;
+            std::map<int, std::string> unitNames;
+            size_t j = i;
             while (j < st->members.size() && st->members[j].isBitfield()) {
-                totalBits += st->members[j].bitWidth;
+                const auto& bitMember = st->members[j];
                 j++;
-            }
+                if (bitMember.name.empty() || bitMember.bitWidth == 0) continue;
 
-            
-            long long packedValue = 0;
-            int bitOffset = 0;
-            for (size_t k = i; k < j; ++k) {
+                int unitIndex = layout.memberIndices[bitMember.name];
+                if (!unitNames.count(unitIndex)) {
+                    unitNames[unitIndex] = bitMember.name;
+                    unitValues[unitIndex] = 0;
+                }
                 if (idx < flatList->initializers.size()) {
                     long long val;
                     if (evaluateConstantExpr(flatList->initializers[idx].get(), val)) {
-                        
-                        long long mask = (1LL << st->members[k].bitWidth) - 1;
-                        packedValue |= (val & mask) << bitOffset;
+                        long long mask = (bitMember.bitWidth >= 64) ? -1LL : ((1LL << bitMember.bitWidth) - 1);
+                        unitValues[unitIndex] |= (val & mask) << layout.bitfieldOffsets[bitMember.name];
                     }
                     idx++;
                 }
-                bitOffset += st->members[k].bitWidth;
             }
-            result += memberTypeStr + " " + std::to_string(packedValue);
 
-            
-            int packingBytes = totalBits / 8;
-            if (packingBytes == 1 && j < st->members.size()) {
-                
-                result += ", [3 x i8] zeroinitializer";
+            for (const auto& unit : unitValues) {
+                beginField(unitNames[unit.first]);
+                result += layout.memberTypes[unitNames[unit.first]] + " " + std::to_string(unit.second);
             }
 
             i = j;
-        } else {
-            
-            size_t memberInitCount = countFlattedMembers(memberType);
-
-            // For union members, use special handling 
-            if (auto* structMemberType = dynamic_cast<AST::StructType*>(memberType)) {
-                if (structMemberType->isUnion) {
-                    // For unions, just use a single byte value
-                    result += memberTypeStr + " ";
-                    if (idx < flatList->initializers.size()) {
-                        long long val;
-                        if (evaluateConstantExpr(flatList->initializers[idx].get(), val)) {
-                            result += std::to_string(val & 0xFF);
-                        } else {
-                            result += getDefaultValue(memberType);
-                        }
-                        idx++;
-                    } else {
-                        result += getDefaultValue(memberType);
-                    }
-                    i++;
-                    continue;
-                }
-            }
+            continue;
+        }
 
-            // For non-union members, continue with normal logic
-            result += memberTypeStr + " ";
+        beginField(member.name);
+        result += memberTypeStr + " ";
 
-            if (idx >= flatList->initializers.size()) {
-                
-                result += getDefaultValue(memberType);
-            } else if (memberInitCount == 1) {
-                
-                long long val;
-                if (evaluateConstantExpr(flatList->initializers[idx].get(), val)) {
-                    
-                    if (auto* structMemberType = dynamic_cast<AST::StructType*>(memberType)) {
-                        
-                            
-                            if (structMemberType->members.empty()) {
-                                result += getDefaultValue(memberType);
-                            } else {
-                                result += "{ ";
-                                if (!structMemberType->members.empty()) {
-                                    const auto& firstMember = structMemberType->members[0];
-                                    std::string firstMemberType = typeToLLVM(stripQualifiers(firstMember.type.get()));
-                                    result += firstMemberType + " ";
-                                    
-                                    if (auto* primType =
-                                            dynamic_cast<AST::PrimitiveType*>(stripQualifiers(firstMember.type.get()))) {
-                                        if (primType->kind == AST::PrimitiveKind::Float ||
-                                            primType->kind == AST::PrimitiveKind::Double ||
-                                            primType->kind == AST::PrimitiveKind::LongDouble) {
-                                            result += std::to_string(val) + ".0";
-                                        } else {
-                                            result += std::to_string(val);
-                                        }
-                                    } else {
-                                        result += std::to_string(val);
-                                    }
-                                }
-                                result += " }";
-                            }
-                    } else {
-                        // Non-struct member - value already has type prepended at line 96
-                        if (auto* primType = dynamic_cast<AST::PrimitiveType*>(memberType)) {
-                            if (primType->kind == AST::PrimitiveKind::Float ||
-                                primType->kind == AST::PrimitiveKind::Double ||
-                                primType->kind == AST::PrimitiveKind::LongDouble) {
-                                
-                                result += std::to_string(val) + ".0";
-                            } else {
-                                result += std::to_string(val);
-                            }
-                        } else {
-                            result += std::to_string(val);
-                        }
-                    }
+        size_t memberInitCount = countFlattedMembers(memberType);
+        AST::Expression* init = idx < flatList->initializers.size() ? flatList->initializers[idx].get() : nullptr;
+        auto* initListExpr = dynamic_cast<AST::InitializerList*>(init);
+        auto* structMemberType = dynamic_cast<AST::StructType*>(memberType);
+        auto* arrayMemberType = dynamic_cast<AST::ArrayType*>(memberType);
+        auto* strLit = dynamic_cast<AST::StringLiteral*>(init);
+
+        if (!init) {
+            result += getDefaultValue(memberType);
+        } else if (structMemberType && structMemberType->isUnion) {
+            // Unions are byte arrays: consume their initializers and zero the storage
+            result += getDefaultValue(memberType);
+            if (initListExpr) {
+                idx++;
+            } else {
+                size_t unionInitCount = countFlattedMembers(memberType);
+                for (size_t k = 0; k < unionInitCount && idx < flatList->initializers.size(); ++k) {
                     idx++;
-                } else {
-                    
-                    if (auto* initListExpr = dynamic_cast<AST::InitializerList*>(flatList->initializers[idx].get())) {
-                        
-                        
-                        if (initListExpr->initializers.empty()) {
-                            result += getDefaultValue(memberType);
-                        } else {
-                            
-                            result += generateInitializerValue(memberType, initListExpr);
-                        }
-                        idx++;
-                    } else {
-                        result += getDefaultValue(memberType);
-                        idx++;
-                    }
                 }
+            }
+        } else if (initListExpr) {
+            if (initListExpr->initializers.empty()) {
+                result += getDefaultValue(memberType);
             } else {
-                
-                if (auto* structMemberType = dynamic_cast<AST::StructType*>(memberType)) {
-                    // For unions, use zeroinitializer (unions are now byte arrays)
-                    if (structMemberType->isUnion) {
-                        result += memberTypeStr + " " + getDefaultValue(memberType);
-                        // Skip all initializers for this union member
-                        size_t unionInitCount = countFlattedMembers(memberType);
-                        for (size_t k = 0; k < unionInitCount && idx < flatList->initializers.size(); ++k) {
-                            idx++;
-                        }
-                    } else {
-                        if (idx < flatList->initializers.size()) {
-                            if (auto* initListExpr = dynamic_cast<AST::InitializerList*>(flatList->initializers[idx].get())) {
-                                
-                                result += generateInitializerValue(memberType, initListExpr);
-                                idx++;
-                            } else {
-                                
-                                result += generateStructInitializerFromFlatHelper(structMemberType, flatList, idx);
-                            }
-                        } else {
-                            result += getDefaultValue(memberType);
-                        }
-                    }
-                } else if (auto* arrayMemberType = dynamic_cast<AST::ArrayType*>(memberType)) {
-                    
+                result += generateInitializerValue(memberType, initListExpr);
+            }
+            idx++;
+        } else if (arrayMemberType && strLit && isCharArrayType(arrayMemberType)) {
+            result += generateStringArrayInitializer(arrayMemberType, strLit);
+            idx++;
+        } else if (structMemberType) {
+            result += generateStructInitializerFromFlatHelper(structMemberType, flatList, idx);
+        } else if (arrayMemberType) {
+            long long length = getArrayLength(arrayMemberType);
+            size_t elemCount = (length > 0) ? static_cast<size_t>(length) : 0;
+            if (elemCount > 0) {
+                result += "[";
+                AST::Type* elemAstType = arrayMemberType->elementType.get();
+                std::string elemTypeStr = typeToLLVM(elemAstType);
+                for (size_t k = 0; k < elemCount; ++k) {
+                    if (k > 0) result += ", ";
+                    result += elemTypeStr + " ";
                     if (idx < flatList->initializers.size()) {
-                        if (auto* initListExpr = dynamic_cast<AST::InitializerList*>(flatList->initializers[idx].get())) {
-                            
-                            result += generateInitializerValue(memberType, initListExpr);
-                            idx++;
-                        } else {
-                            
-                            size_t elemCount = (arrayMemberType->size > 0) ? arrayMemberType->size : 0;
-                            if (elemCount > 0) {
-                                result += "[";
-                                std::string elemTypeStr = typeToLLVM(arrayMemberType->elementType.get());
-                                for (size_t k = 0; k < elemCount; ++k) {
-                                    if (k > 0) result += ", ";
-                                    result += elemTypeStr + " ";
-                                    if (idx < flatList->initializers.size()) {
-                                        long long val;
-                                        if (evaluateConstantExpr(flatList->initializers[idx].get(), val)) {
-                                            result += std::to_string(val);
-                                        } else {
-                                            result += "0";
-                                        }
-                                        idx++;
-                                    } else {
-                                        result += "0";
-                                    }
-                                }
-                                result += "]";
-                            } else {
-                                result += getDefaultValue(memberType);
-                            }
-                        }
+                        result += generateScalarInitializerValue(elemAstType, flatList->initializers[idx].get());
+                        idx++;
                     } else {
-                        result += getDefaultValue(memberType);
+                        result += getDefaultValue(elemAstType);
                     }
-                } else {
-                    result += getDefaultValue(memberType);
                 }
+                result += "]";
+            } else {
+                result += getDefaultValue(memberType);
             }
-            i++;
+        } else if (memberInitCount == 1) {
+            result += generateScalarInitializerValue(memberType, init);
+            idx++;
+        } else {
+            result += getDefaultValue(memberType);
         }
+        i++;
     }
 
+    padTo(layout.fieldCount);
+
     result += " }";
     return result;
 }
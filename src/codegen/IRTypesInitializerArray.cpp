@@ -9,15 +9,9 @@ std::string IRGenerator::generateArrayInitializerValue(AST::ArrayType* arrayType
 
     size_t arraySize = 0;
 
-    
-    if (arrayType->size >= 0) {
-        arraySize = static_cast<size_t>(arrayType->size);
-    } else if (arrayType->sizeExpr) {
-        
-        long long sz;
-        if (evaluateConstantExpr(arrayType->sizeExpr.get(), sz)) {
-            arraySize = static_cast<size_t>(sz);
-        }
+    long long length = getArrayLength(arrayType);
+    if (length > 0) {
+        arraySize = static_cast<size_t>(length);
     }
 
     
@@ -33,6 +27,7 @@ std::string IRGenerator::generateArrayInitializerValue(AST::ArrayType* arrayType
     auto* structType = dynamic_cast<AST::StructType*>(stripQualifiers(arrayType->elementType.get()));
 
     if (structType) {
+        structType = resolveStructDefinition(structType);
         return generateArrayInitializerStruct(arrayType, structType, initList, arraySize, elemType);
     }
     return generateArrayInitializerNonStruct(arrayType, structType, initList, arraySize, elemType);
@@ -96,8 +91,12 @@ std::string IRGenerator::generateArrayInitializerNonStruct(AST::ArrayType* array
 
         if (i < initList->initializers.size()) {
             
+            auto* elemArrayType = dynamic_cast<AST::ArrayType*>(stripQualifiers(arrayType->elementType.get()));
+            auto* elemStrLit = dynamic_cast<AST::StringLiteral*>(initList->initializers[i].get());
             if (auto* elemInitList = dynamic_cast<AST::InitializerList*>(initList->initializers[i].get())) {
                 result += elemType + " " + generateInitializerValue(arrayType->elementType.get(), elemInitList);
+            } else if (elemStrLit && isCharArrayType(elemArrayType)) {
+                result += elemType + " " + generateStringArrayInitializer(elemArrayType, elemStrLit);
             } else {
                 
                 if (structType) {
@@ -128,21 +127,16 @@ std::string IRGenerator::generateArrayInitializerNonStruct(AST::ArrayType* array
                     long long val;
                     if (evaluateConstantExpr(initList->initializers[i].get(), val)) {
                         
-                        size_t elemArraySize = 0;
-                        if (arrayElemType->size >= 0) {
-                            elemArraySize = static_cast<size_t>(arrayElemType->size);
-                        } else if (arrayElemType->sizeExpr) {
-                            long long sz;
-                            if (evaluateConstantExpr(arrayElemType->sizeExpr.get(), sz)) {
-                                elemArraySize = static_cast<size_t>(sz);
-                            }
-                        }
+                        long long elemLength = getArrayLength(arrayElemType);
+                        size_t elemArraySize = elemLength > 0 ? static_cast<size_t>(elemLength) : 0;
                         std::string elemArrayType = typeToLLVM(arrayElemType->elementType.get());
                         std::string arrayInit = "[";
                         for (size_t j = 0; j < elemArraySize; ++j) {
                             if (j > 0) arrayInit += ", ";
                             arrayInit += elemArrayType + " ";
-                            arrayInit += (j == 0) ? std::to_string(val) : "0";
+                            arrayInit += (j == 0) ? generateScalarInitializerValue(arrayElemType->elementType.get(),
+                                                                                  initList->initializers[i].get())
+                                                  : getDefaultValue(arrayElemType->elementType.get());
                         }
                         arrayInit += "]";
                         result += elemType + " " + arrayInit;
@@ -151,17 +145,14 @@ std::string IRGenerator::generateArrayInitializerNonStruct(AST::ArrayType* array
                     }
                 } else {
                     
-                    long long val;
-                    if (evaluateConstantExpr(initList->initializers[i].get(), val)) {
-                        result += elemType + " " + std::to_string(val);
-                    } else {
-                        result += elemType + " " + "zeroinitializer";
-                    }
+                    result += elemType + " " +
+                              generateScalarInitializerValue(arrayType->elementType.get(),
+                                                             initList->initializers[i].get());
                 }
             }
         } else {
             
-            result += elemType + " 0";
+            result += elemType + " " + getDefaultValue(arrayType->elementType.get());
         }
     }
 
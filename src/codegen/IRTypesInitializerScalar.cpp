@@ -0,0 +1,69 @@
+#include <codegen/IRGenerator.hpp>
+
+#include <iomanip>
+#include <sstream>
+
+namespace cc1 {
+
+// EN: Folds a scalar initializer element to an LLVM constant of the given type.
+// FR: Evalue un element d init scalaire en constante LLVM du type donne.
+std::string IRGenerator::generateScalarInitializerValue(AST::Type* type, AST::Expression* expr) {
+    AST::Type* base = stripQualifiers(type);
+
+    if (auto* prim = dynamic_cast<AST::PrimitiveType*>(base)) {
+        if (prim->kind == AST::PrimitiveKind::Float ||
+            prim->kind == AST::PrimitiveKind::Double ||
+            prim->kind == AST::PrimitiveKind::LongDouble) {
+            double fpVal;
+            if (!evaluateConstantFloatExpr(expr, fpVal)) {
+                return getDefaultValue(type);
+            }
+            if (prim->kind == AST::PrimitiveKind::Float) {
+                return formatLLVMFloatConstant(static_cast<float>(fpVal));
+            }
+            std::ostringstream oss;
+            oss.setf(std::ios::scientific);
+            oss << std::setprecision(17) << fpVal;
+            return oss.str();
+        }
+    }
+
+    long long val;
+    if (!evaluateConstantExpr(expr, val)) {
+        return getDefaultValue(type);
+    }
+
+    if (dynamic_cast<AST::PointerType*>(base)) {
+        if (val == 0) return "null";
+        std::string intType = is64bit_ ? "i64" : "i32";
+        return "inttoptr (" + intType + " " + std::to_string(val) + " to " + typeToLLVM(type) + ")";
+    }
+
+    return std::to_string(val);
+}
+
+// EN: Checks whether an array has char, signed char or unsigned char elements.
+// FR: Verifie si un tableau a des elements char, signed char ou unsigned char.
+bool IRGenerator::isCharArrayType(AST::ArrayType* arrayType) {
+    if (!arrayType) return false;
+    auto* prim = dynamic_cast<AST::PrimitiveType*>(stripQualifiers(arrayType->elementType.get()));
+    if (!prim) return false;
+    return prim->kind == AST::PrimitiveKind::Char ||
+           prim->kind == AST::PrimitiveKind::SignedChar ||
+           prim->kind == AST::PrimitiveKind::UnsignedChar;
+}
+
+// EN: Builds a c"..." constant for a char array, truncating or zero-filling to its length.
+// FR: Construit une constante c"..." pour un tableau de char, tronquee ou completee par des zeros.
+std::string IRGenerator::generateStringArrayInitializer(AST::ArrayType* arrayType, AST::StringLiteral* strLit) {
+    long long length = getArrayLength(arrayType);
+    std::string bytes = strLit ? strLit->value : std::string();
+    if (length < 0) {
+        length = static_cast<long long>(bytes.size()) + 1;
+    }
+
+    bytes.resize(static_cast<size_t>(length), '\0');
+    return "c\"" + escapeIRString(bytes) + "\"";
+}
+
+} 
@@ -12,7 +12,7 @@ AST::Type* IRGenerator::stripQualifiers(AST::Type* type) {
     }
     
     while (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
-        AST::Type* resolved = resolveTypedef(typedefType->name);
+        AST::Type* resolved = resolveTypedef(typedefType);
         if (resolved) {
             type = resolved;
         } else {
@@ -22,6 +22,42 @@ AST::Type* IRGenerator::stripQualifiers(AST::Type* type) {
     return type;
 }
 
+// EN: Checks whether an object of this type is const (arrays inherit element constness).
+// FR: Verifie si un objet de ce type est const (les tableaux heritent de leurs elements).
+bool IRGenerator::isConstObjectType(AST::Type* type) {
+    while (type) {
+        if (auto* qual = dynamic_cast<AST::QualifiedType*>(type)) {
+            if (qual->isConst) return true;
+            type = qual->baseType.get();
+        } else if (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
+            type = resolveTypedef(typedefType);
+        } else if (auto* arrayType = dynamic_cast<AST::ArrayType*>(type)) {
+            type = arrayType->elementType.get();
+        } else {
+            return false;
+        }
+    }
+    return false;
+}
+
+// EN: Checks whether an object of this type is volatile (arrays inherit element volatility).
+// FR: Verifie si un objet de ce type est volatile (les tableaux heritent de leurs elements).
+bool IRGenerator::isVolatileObjectType(AST::Type* type) {
+    while (type) {
+        if (auto* qual = dynamic_cast<AST::QualifiedType*>(type)) {
+            if (qual->isVolatile) return true;
+            type = qual->baseType.get();
+        } else if (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
+            type = resolveTypedef(typedefType);
+        } else if (auto* arrayType = dynamic_cast<AST::ArrayType*>(type)) {
+            type = arrayType->elementType.get();
+        } else {
+            return false;
+        }
+    }
+    return false;
+}
+
 // EN: Resolves a typedef name to its underlying type if known.
 // FR: Resout un typedef vers son type sous-jacent si connu.
 AST::Type* IRGenerator::resolveTypedef(const std::string& name) {
@@ -32,6 +68,14 @@ AST::Type* IRGenerator::resolveTypedef(const std::string& name) {
     return nullptr;
 }
 
+// EN: Resolves a typedef use, preferring the binding captured by the parser (handles shadowing).
+// FR: Resout un usage de typedef, en preferant la liaison capturee par le parseur (gere le masquage).
+AST::Type* IRGenerator::resolveTypedef(AST::TypedefType* type) {
+    if (!type) return nullptr;
+    if (type->underlyingType) return type->underlyingType.get();
+    return resolveTypedef(type->name);
+}
+
 // EN: Extracts a field type from an inline LLVM struct type string.
 // FR: Extrait le type d un champ depuis un type struct LLVM inline.
 std::string IRGenerator::extractFieldTypeFromInlineStruct(const std::string& inlineStructType, int fieldIndex) {
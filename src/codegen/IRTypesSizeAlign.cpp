@@ -11,7 +11,7 @@ int IRGenerator::getTypeSize(AST::Type* type) {
 
     
     if (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
-        AST::Type* resolved = resolveTypedef(typedefType->name);
+        AST::Type* resolved = resolveTypedef(typedefType);
         if (resolved) {
             return getTypeSize(resolved);
         }
@@ -55,6 +55,19 @@ int IRGenerator::getTypeSize(AST::Type* type) {
     return 4;  
 }
 
+// EN: Returns the array length from its resolved size or constant size expression.
+// FR: Renvoie la longueur du tableau depuis sa taille resolue ou son expression constante.
+long long IRGenerator::getArrayLength(AST::ArrayType* arrayType) {
+    if (!arrayType) return -1;
+    if (arrayType->size >= 0) return arrayType->size;
+
+    long long size = -1;
+    if (arrayType->sizeExpr && evaluateConstantExpr(arrayType->sizeExpr.get(), size)) {
+        return size;
+    }
+    return -1;
+}
+
 // EN: Computes alignment for a type using target pointer width.
 // FR: Calcule l alignement d un type selon la cible.
 int IRGenerator::getTypeAlign(AST::Type* type) {
@@ -64,7 +77,7 @@ int IRGenerator::getTypeAlign(AST::Type* type) {
 
     
     if (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
-        AST::Type* resolved = resolveTypedef(typedefType->name);
+        AST::Type* resolved = resolveTypedef(typedefType);
         if (resolved) {
             return getTypeAlign(resolved);
         }
@@ -115,6 +115,13 @@ IRGenerator::StructLayout IRGenerator::computeStructLayout(AST::StructDecl* decl
         layout.llvmType += " }";
         layout.totalSize = finalSize > 0 ? finalSize : 1;
         layout.alignment = maxAlign;
+        layout.fieldCount = fieldIndex;
+    }
+
+    for (const auto& member : decl->members) {
+        if (member && isVolatileObjectType(member->type.get())) {
+            layout.volatileMembers.insert(member->name);
+        }
     }
 
     return layout;
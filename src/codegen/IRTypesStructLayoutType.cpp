@@ -9,10 +9,13 @@ IRGenerator::StructLayout IRGenerator::computeStructLayout(AST::StructType* type
         return StructLayout{};
     }
 
-    if (type->isUnion) {
-        return computeStructLayoutUnion(type);
+    StructLayout layout = type->isUnion ? computeStructLayoutUnion(type) : computeStructLayoutStruct(type);
+    for (const auto& member : type->members) {
+        if (!member.name.empty() && isVolatileObjectType(member.type.get())) {
+            layout.volatileMembers.insert(member.name);
+        }
     }
-    return computeStructLayoutStruct(type);
+    return layout;
 }
 
 } 
@@ -210,6 +210,7 @@ IRGenerator::StructLayout IRGenerator::computeStructLayoutStruct(AST::StructType
     layout.llvmType += " }";
     layout.totalSize = finalSize > 0 ? finalSize : 1;
     layout.alignment = maxAlign;
+    layout.fieldCount = fieldIndex;
 
     return layout;
 }
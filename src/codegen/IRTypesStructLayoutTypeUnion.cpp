@@ -68,6 +68,7 @@ IRGenerator::StructLayout IRGenerator::computeStructLayoutUnion(AST::StructType*
     layout.llvmType += " }";
     layout.totalSize = desiredSize;
     layout.alignment = maxAlign;
+    layout.fieldCount = 1;
     layout.isUnion = true;
 
     return layout;
@@ -12,6 +12,20 @@ const IRGenerator::StructLayout* IRGenerator::getStructLayout(const std::string&
     return nullptr;
 }
 
+// EN: Returns the complete definition behind a bare 'struct S' reference.
+// FR: Renvoie la definition complete derriere une reference 'struct S'.
+AST::StructType* IRGenerator::resolveStructDefinition(AST::StructType* structType) {
+    if (!structType || !structType->members.empty() || structType->name.empty()) {
+        return structType;
+    }
+
+    auto it = namedStructDefs_.find(structType->name);
+    if (it != namedStructDefs_.end() && it->second.second && !it->second.second->members.empty()) {
+        return it->second.second;
+    }
+    return structType;
+}
+
 // EN: Registers a named struct and its nested structs for codegen.
 // FR: Enregistre une struct nommee et ses structs imbriquees.
 void IRGenerator::collectNamedStruct(AST::StructType* structType) {
@@ -24,8 +38,15 @@ void IRGenerator::collectNamedStruct(AST::StructType* structType) {
     }
 
     
-    if (namedStructDefs_.count(structType->name)) {
-        return;
+    // EN: A later complete definition replaces an earlier 'struct S' forward reference.
+    // FR: Une definition complete ulterieure remplace une reference anticipee 'struct S'.
+    auto existing = namedStructDefs_.find(structType->name);
+    if (existing != namedStructDefs_.end()) {
+        AST::StructType* known = existing->second.second;
+        if (structType->members.empty() || (known && !known->members.empty())) {
+            return;
+        }
+        structLayouts_.erase(structType->name);
     }
 
     
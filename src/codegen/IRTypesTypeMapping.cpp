@@ -67,7 +67,7 @@ std::string IRGenerator::typeToLLVM(AST::Type* type) {
 
     if (auto* typedefType = dynamic_cast<AST::TypedefType*>(type)) {
         
-        AST::Type* resolved = resolveTypedef(typedefType->name);
+        AST::Type* resolved = resolveTypedef(typedefType);
         if (resolved) {
             return typeToLLVM(resolved);
         }
@@ -42,6 +42,12 @@ CompilerOptions ArgumentParser::parse() {
             opts.preprocessOnly = true;
             continue;
         }
+
+        if (arg == "--dump-preprocessed-with-lines") {
+            opts.preprocessOnly = true;
+            opts.lineMarkers = true;
+            continue;
+        }
         
         if (arg == "-debug") {
             opts.debugMode = true;
@@ -95,6 +101,14 @@ CompilerOptions ArgumentParser::parse() {
             continue;
         }
         
+        if (arg == "-include") {
+            if (i + 1 >= argc_) {
+                throw std::invalid_argument("missing filename after '-include'");
+            }
+            opts.forceIncludes.push_back(argv_[++i]);
+            continue;
+        }
+        
         if (arg == "-o") {
             parseOutputOption(i, opts);
         } else if (arg.substr(0, 2) == "-o") {
@@ -160,6 +174,9 @@ void ArgumentParser::printUsage(const char* programName) {
               << "       " CYAN "-E" RESET "\n"
               << "              Preprocess only; write preprocessed source to stdout (or -o).\n"
               << "\n"
+              << "       " CYAN "--dump-preprocessed-with-lines" RESET "\n"
+              << "              Like " CYAN "-E" RESET ", with GNU-style '# line \"file\"' markers.\n"
+              << "\n"
               << "       " CYAN "-o" RESET " " GREEN "<file>" RESET "\n"
               << "              Write output to " GREEN "<file>" RESET ".\n"
               << "\n"
@@ -172,6 +189,9 @@ void ArgumentParser::printUsage(const char* programName) {
               << "       " CYAN "-I" RESET " " GREEN "<dir>" RESET "\n"
               << "              Add directory to include search path.\n"
               << "\n"
+              << "       " CYAN "-include" RESET " " GREEN "<file>" RESET "\n"
+              << "              Process " GREEN "<file>" RESET " before the main source (repeatable).\n"
+              << "\n"
               << "       " CYAN "-g" RESET "\n"
               << "              Emit debug information (LLVM debug metadata).\n"
               << "\n"
@@ -17,12 +17,14 @@ CompilerDriver::CompilerDriver(const CompilerOptions& opts)
       output_file_(opts.outputFile),
       syntax_only_(opts.syntaxOnly),
       preprocess_only_(opts.preprocessOnly),
+      line_markers_(opts.lineMarkers),
       debug_mode_(opts.debugMode),
             debug_info_(opts.debugInfo),
     is64bit_(opts.is64bit),
       defines_(opts.defines),
       undefines_(opts.undefines),
-      include_paths_(opts.includePaths)
+      include_paths_(opts.includePaths),
+      force_includes_(opts.forceIncludes)
 {
     symbols_.reset(new SymbolTable());
     
@@ -102,6 +104,7 @@ bool CompilerDriver::runParsing()
     
     try {
         cc1::Parser parser(tokens_, filename, diagnosticSource());
+        parser.declareBuiltinTypedefs(is64bit_);
         ast_ = parser.parse();
         
         
@@ -51,6 +51,7 @@ bool CompilerDriver::runPreprocessing()
     }
 
     cc1::Preprocessor preprocessor;
+    preprocessor.setLineMarkers(line_markers_);
 
     
     for (const auto& path : include_paths_) {
@@ -74,6 +75,16 @@ bool CompilerDriver::runPreprocessing()
 
     
     source_.clear();
+
+    // EN: -include headers run first and share the macro table with the main source.
+    // FR: Les en-tetes -include passent en premier et partagent la table des macros.
+    for (const auto& header : force_includes_) {
+        source_ += preprocessor.preprocessForcedInclude(header);
+        if (preprocessor.hadError()) {
+            return false;
+        }
+    }
+
     for (const auto& filename : input_files_) {
         std::string fileSource;
         std::string originalSource;
@@ -45,6 +45,9 @@ void TernaryExpr::accept(Visitor& visitor) { visitor.visit(*this); }
 // EN: Dispatches visitor for initializer list nodes.
 // FR: Dispatch le visiteur pour les listes d initialisation.
 void InitializerList::accept(Visitor& visitor) { visitor.visit(*this); }
+// EN: Dispatches visitor for va_arg expression nodes.
+// FR: Dispatch le visiteur pour les expressions va_arg.
+void VaArgExpr::accept(Visitor& visitor) { visitor.visit(*this); }
 
 } 
 
@@ -20,6 +20,7 @@ Parser::Parser(const std::vector<Token>& tokens,
     , filename_(filename)
     , source_(source)
 {
+    pushTypedefScope();
 }
 
 
@@ -33,8 +34,8 @@ AST::Ptr<AST::TranslationUnit> Parser::parse() {
     
     while (!isAtEnd()) {
         try {
-            auto decl = parseExternalDeclaration();
-            if (decl) {
+            auto decls = parseDeclarations();
+            for (auto& decl : decls) {
                 unit->declarations.push_back(std::move(decl));
             }
         } catch (const ParseError& e) {
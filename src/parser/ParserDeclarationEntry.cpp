@@ -17,10 +17,12 @@ std::vector<AST::Ptr<AST::Declaration>> Parser::parseDeclarations() {
         result.push_back(std::move(first));
     }
     
-    while (!additionalDeclarations_.empty()) {
-        result.push_back(std::move(additionalDeclarations_.back()));
-        additionalDeclarations_.pop_back();
+    // EN: Keeps source order so 'int n = 2, m = n;' sees n before m.
+    // FR: Garde l ordre source pour que 'int n = 2, m = n;' voie n avant m.
+    for (auto& extra : additionalDeclarations_) {
+        result.push_back(std::move(extra));
     }
+    additionalDeclarations_.clear();
     return result;
 }
 
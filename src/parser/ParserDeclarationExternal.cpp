@@ -79,6 +79,20 @@ AST::Ptr<AST::Declaration> Parser::parseExternalDeclaration() {
     }
 
     Declarator decl = parseDeclarator(specs.type);
+    if (!specs.isTypedef) {
+        declareOrdinaryName(decl.name);
+    }
+
+    // EN: Clones drop enumerator lists, so 'enum { A, B } e;' keeps the defining type
+    // on its first declarator; later declarators get a plain clone.
+    // FR: Les clones perdent les enumerateurs: 'enum { A, B } e;' garde le type
+    // definissant sur le premier declarateur; les suivants ont un simple clone.
+    auto* specEnum = dynamic_cast<AST::EnumType*>(specs.type.get());
+    if (specEnum && !specEnum->enumerators.empty() && dynamic_cast<AST::EnumType*>(decl.type.get())) {
+        AST::Ptr<AST::Type> plain = specs.type->clone();
+        decl.type = std::move(specs.type);
+        specs.type = std::move(plain);
+    }
 
     
     // EN: Check if this is a function declaration or definition.
@@ -149,7 +163,7 @@ AST::Ptr<AST::Declaration> Parser::parseExternalDeclaration() {
             errorAtPosition(decl.line, decl.column, "redefinition of '" + decl.name + "' as different kind of symbol");
         }
 
-        typedefNames_.insert(decl.name);
+        declareTypedefName(decl.name, decl.type.get());
         if (functionDepth_ == 0 && !decl.name.empty()) {
             globalIdentifiers_[decl.name] = "typedef " + (decl.type ? decl.type->toString() : "unknown");
         }
@@ -164,7 +178,7 @@ AST::Ptr<AST::Declaration> Parser::parseExternalDeclaration() {
             if (functionDepth_ == 0 && !nextDecl.name.empty() && globalIdentifiers_.count(nextDecl.name)) {
                 error("redefinition of '" + nextDecl.name + "' as different kind of symbol");
             }
-            typedefNames_.insert(nextDecl.name);
+            declareTypedefName(nextDecl.name, nextDecl.type.get());
             if (functionDepth_ == 0 && !nextDecl.name.empty()) {
                 globalIdentifiers_[nextDecl.name] = "typedef " + (nextDecl.type ? nextDecl.type->toString() : "unknown");
             }
@@ -201,7 +215,7 @@ AST::Ptr<AST::Declaration> Parser::parseExternalDeclaration() {
     
     while (match(TokenType::Comma)) {
         Declarator nextDecl = parseDeclarator(specs.type);
-        
+        declareOrdinaryName(nextDecl.name);
         
         if (functionDepth_ == 0 && !nextDecl.name.empty() && globalIdentifiers_.count(nextDecl.name)) {
             std::string newType = nextDecl.type ? nextDecl.type->toString() : "unknown";
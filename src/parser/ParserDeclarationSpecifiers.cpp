@@ -16,7 +16,9 @@ Parser::DeclSpecifiers Parser::parseDeclarationSpecifiers() {
 
     while (isDeclarationSpecifier()) {
         
-        if (check(TokenType::Typedef)) {
+        if (isIgnoredSpecifier()) {
+            skipIgnoredSpecifier();
+        } else if (check(TokenType::Typedef)) {
             specs.storageClassLine = current().line;
             specs.storageClassColumn = current().column;
             advance();
@@ -88,9 +90,11 @@ Parser::DeclSpecifiers Parser::parseDeclarationSpecifiers() {
         
         else if (!hasTypeSpec && check(TokenType::Identifier)) {
             std::string name = current().value;
-            if (typedefNames_.find(name) != typedefNames_.end()) {
+            if (isTypedefName(name)) {
                 advance();
-                specs.type = AST::make<AST::TypedefType>(name, 0, 0);
+                auto typedefType = AST::make<AST::TypedefType>(name, 0, 0);
+                typedefType->underlyingType = typedefUnderlyingType(name);
+                specs.type = std::move(typedefType);
                 hasTypeSpec = true;
             } else {
                 break;
@@ -152,7 +156,8 @@ Parser::DeclSpecifiers Parser::parseDeclarationSpecifiers() {
 // EN: Checks if current token can start a declaration specifier.
 // FR: Verifie si le token courant peut demarrer un specifier.
 bool Parser::isDeclarationSpecifier() const {
-    return isStorageClassSpecifier() || isTypeSpecifier() || isTypeQualifier();
+    return isStorageClassSpecifier() || isTypeSpecifier() || isTypeQualifier() ||
+           isIgnoredSpecifier();
 }
 
 // EN: Checks if current token is a type specifier or typedef name.
@@ -173,7 +178,7 @@ bool Parser::isTypeSpecifier() const {
         case TokenType::Enum:
             return true;
         case TokenType::Identifier:
-            return typedefNames_.find(current().value) != typedefNames_.end();
+            return isTypedefName(current().value);
         default:
             return false;
     }
@@ -201,6 +206,42 @@ bool Parser::isTypeQualifier() const {
            current().type == TokenType::Volatile;
 }
 
+// EN: Checks for Microsoft calling conventions and __declspec; they are parsed but
+//     ignored, so the default calling convention is used.
+// FR: Verifie les conventions d appel Microsoft et __declspec; elles sont parsees
+//     puis ignorees, la convention d appel par defaut est donc utilisee.
+bool Parser::isIgnoredSpecifier() const {
+    if (current().type != TokenType::Identifier || isTypedefName(current().value)) {
+        return false;
+    }
+    const std::string& name = current().value;
+    return name == "__cdecl" || name == "__stdcall" || name == "__declspec";
+}
+
+// EN: Skips the keyword and, for __declspec, its balanced parenthesized arguments.
+// FR: Saute le mot-cle et, pour __declspec, ses arguments entre parentheses equilibrees.
+void Parser::skipIgnoredSpecifier() {
+    bool isDeclspec = current().value == "__declspec";
+    advance();
+    if (!isDeclspec) {
+        return;
+    }
+
+    consume(TokenType::LeftParen, "expected '(' after '__declspec'");
+    int depth = 1;
+    while (depth > 0 && !check(TokenType::EndOfFile)) {
+        if (check(TokenType::LeftParen)) {
+            ++depth;
+        } else if (check(TokenType::RightParen)) {
+            --depth;
+        }
+        advance();
+    }
+    if (depth > 0) {
+        consume(TokenType::RightParen, "expected ')' after '__declspec' arguments");
+    }
+}
+
 } 
 
 // TODO(cc1) EN: Add diagnostics for invalid specifier combinations.
@@ -1,3 +1,4 @@
+#include <algorithm>
 #include <utility>
 #include <functional>
 #include <parser/Parser.hpp>
@@ -76,6 +77,11 @@ namespace cc1 {
 // EN: Parses a declarator including pointer qualifiers and direct parts.
 // FR: Parse un declarator avec pointeurs/qualifiers et parties directes.
 Parser::Declarator Parser::parseDeclarator(const AST::Ptr<AST::Type>& baseType) {
+    // EN: A calling convention may precede the pointer chain, as in 'void (__cdecl *fp)(void)'.
+    // FR: Une convention d appel peut preceder les pointeurs, comme 'void (__cdecl *fp)(void)'.
+    while (isIgnoredSpecifier()) {
+        skipIgnoredSpecifier();
+    }
     AST::Ptr<AST::Type> type = parsePointer(baseType->clone());
     return parseDirectDeclarator(type);
 }
@@ -126,18 +132,32 @@ Parser::Declarator Parser::parseDirectDeclarator(const AST::Ptr<AST::Type>& base
 
     while (true) {
         if (match(TokenType::LeftBracket)) {
-            
-            AST::Ptr<AST::Expression> size;
-            if (!check(TokenType::RightBracket)) {
-                size = parseConstantExpression();
+            // EN: Collect consecutive dimensions; 'a[2][3]' is an array of 2 arrays of 3.
+            // FR: Collecte les dimensions consecutives; 'a[2][3]' est un tableau de 2 tableaux de 3.
+            std::vector<AST::Ptr<AST::Expression>> sizes;
+            do {
+                AST::Ptr<AST::Expression> size;
+                if (!check(TokenType::RightBracket)) {
+                    size = parseConstantExpression();
+                }
+                consume(TokenType::RightBracket, "expected ']' after array dimension");
+                sizes.push_back(std::move(size));
+            } while (match(TokenType::LeftBracket));
+
+            // EN: Without parentheses the innermost dimension wraps the base type first;
+            //     wrapDerived already descends to the innermost element itself.
+            // FR: Sans parentheses la dimension la plus interne enveloppe d abord le type;
+            //     wrapDerived descend deja lui-meme jusqu a l element le plus interne.
+            if (!wasParenthesized) {
+                std::reverse(sizes.begin(), sizes.end());
+            }
+            for (auto& size : sizes) {
+                auto makeArray = [&](AST::Ptr<AST::Type> base) {
+                    return AST::make<AST::ArrayType>(std::move(base),
+                                                     std::move(size), 0, 0);
+                };
+                applyDerived(makeArray);
             }
-            consume(TokenType::RightBracket, "expected ']' after array dimension");
-
-            auto makeArray = [&](AST::Ptr<AST::Type> base) {
-                return AST::make<AST::ArrayType>(std::move(base),
-                                                 std::move(size), 0, 0);
-            };
-            applyDerived(makeArray);
         } else if (match(TokenType::LeftParen)) {
             
             bool isVariadic = false;
@@ -183,6 +203,8 @@ AST::Ptr<AST::Type> Parser::parsePointer(AST::Ptr<AST::Type> baseType) {
                 isConst = true;
             } else if (match(TokenType::Volatile)) {
                 isVolatile = true;
+            } else if (isIgnoredSpecifier()) {
+                skipIgnoredSpecifier();
             } else {
                 break;
             }
@@ -62,17 +62,20 @@ AST::Ptr<AST::Expression> Parser::parseSizeofExpression() {
     int line = previous().line;
     int col = previous().column;
 
-    if (match(TokenType::LeftParen)) {
+    if (check(TokenType::LeftParen)) {
+        // EN: One token of lookahead: a visible typedef name makes this `sizeof(type)`.
+        // FR: Un token d avance : un nom de typedef visible en fait un `sizeof(type)`.
+        size_t saved = currentIndex_;
+        advance();
         if (isTypeName()) {
             auto type = parseTypeName();
             consume(TokenType::RightParen, "expected ')' after type name");
             return AST::make<AST::SizeofExpr>(std::move(type), line, col);
         }
 
-        
-        auto expr = parseExpression();
-        consume(TokenType::RightParen, "expected ')'");
-        return AST::make<AST::SizeofExpr>(std::move(expr), line, col);
+        // EN: Otherwise the parentheses belong to the unary operand, e.g. `sizeof (a)[0]`.
+        // FR: Sinon les parentheses font partie de l operande unaire, ex. `sizeof (a)[0]`.
+        currentIndex_ = saved;
     }
 
     
@@ -21,6 +21,17 @@ AST::Ptr<AST::Expression> Parser::parsePrimaryExpression() {
         return parsePrimaryCharLiteral();
     }
 
+    if (check(TokenType::Identifier) && current().value == "__builtin_va_arg") {
+        Token tok = current();
+        advance();
+        consume(TokenType::LeftParen, "expected '(' after __builtin_va_arg");
+        auto ap = parseAssignmentExpression();
+        consume(TokenType::Comma, "expected ',' after va_list argument");
+        auto type = parseTypeName();
+        consume(TokenType::RightParen, "expected ')' after va_arg type");
+        return AST::make<AST::VaArgExpr>(std::move(ap), std::move(type), tok.line, tok.column);
+    }
+
     if (check(TokenType::Identifier)) {
         Token tok = current();
         advance();
@@ -1,5 +1,6 @@
 #include <parser/Parser.hpp>
 #include <utils/Diagnostic.hpp>
+#include <utils/Escape.hpp>
 
 namespace cc1 {
 
@@ -31,7 +32,7 @@ AST::Ptr<AST::Expression> Parser::parsePrimaryCharLiteral() {
         // Simple one-char escapes: '\n', '\t', '\'', '\\', etc.
         switch (r[1]) {
             case 'n': case 't': case 'r': case '0':
-            case '\\': case '"': case '\'':
+            case '\\': case '"': case '\'': case '?':
             case 'a': case 'b': case 'f': case 'v':
                 return r.size() == 2;
 
@@ -88,64 +89,8 @@ AST::Ptr<AST::Expression> Parser::parsePrimaryCharLiteral() {
     int value = 0;
     if (!raw.empty()) {
         if (raw[0] == '\\' && raw.size() > 1) {
-            switch (raw[1]) {
-                case 'n':
-                    value = '\n';
-                    break;
-                case 't':
-                    value = '\t';
-                    break;
-                case 'r':
-                    value = '\r';
-                    break;
-                case '0':
-                    value = '\0';
-                    break;
-                case '\\':
-                    value = '\\';
-                    break;
-                case '"':
-                    value = '"';
-                    break;
-                case '\'':
-                    value = '\'';
-                    break;
-                case 'a':
-                    value = '\a';
-                    break;
-                case 'b':
-                    value = '\b';
-                    break;
-                case 'f':
-                    value = '\f';
-                    break;
-                case 'v':
-                    value = '\v';
-                    break;
-                case 'x':
-                    if (raw.size() > 3) {
-                        std::string hex = raw.substr(2, 2);
-                        try {
-                            value = std::stoi(hex, nullptr, 16);
-                        } catch (...) {
-                            value = raw[1];
-                        }
-                    }
-                    break;
-                default:
-                    if (raw[1] >= '0' && raw[1] <= '7') {
-                        std::string oct = raw.substr(1);
-                        if (oct.size() > 3) oct = oct.substr(0, 3);
-                        try {
-                            value = std::stoi(oct, nullptr, 8);
-                        } catch (...) {
-                            value = raw[1];
-                        }
-                    } else {
-                        value = raw[1];
-                    }
-                    break;
-            }
+            size_t pos = 0;
+            value = utils::decode_escape(raw, pos);
         } else {
             value = static_cast<unsigned char>(raw[0]);
         }
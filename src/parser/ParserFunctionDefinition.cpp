@@ -33,9 +33,16 @@ AST::Ptr<AST::FunctionDecl> Parser::parseFunctionDefinition(
     func->isDefinition = true;
 
     
+    // EN: Parameters are ordinary identifiers visible throughout the body.
+    // FR: Les parametres sont des identifiants ordinaires visibles dans tout le corps.
+    pushTypedefScope();
+    for (const auto& param : func->parameters) {
+        if (param) declareOrdinaryName(param->name);
+    }
     functionDepth_++;
     func->body = parseCompoundStatement();
     functionDepth_--;
+    popTypedefScope();
 
     return func;
 }
@@ -33,7 +33,9 @@ AST::Ptr<AST::Statement> Parser::parseWhileStatement() {
     auto condition = parseExpression();
     consume(TokenType::RightParen, "expected ')' after condition");
 
+    loopDepth_++;
     auto body = parseStatement();
+    loopDepth_--;
 
     return AST::make<AST::WhileStmt>(std::move(condition), std::move(body), line, col);
 }
@@ -44,7 +46,9 @@ AST::Ptr<AST::Statement> Parser::parseDoWhileStatement() {
     int line = previous().line;
     int col = previous().column;
 
+    loopDepth_++;
     auto body = parseStatement();
+    loopDepth_--;
 
     consume(TokenType::While, "expected 'while' after do body");
     consume(TokenType::LeftParen, "expected '(' after 'while'");
@@ -81,7 +85,9 @@ AST::Ptr<AST::Statement> Parser::parseForStatement() {
     }
     consume(TokenType::RightParen, "expected ')' after for clauses");
 
+    loopDepth_++;
     auto body = parseStatement();
+    loopDepth_--;
 
     return AST::make<AST::ForStmt>(std::move(init), std::move(condition), std::move(increment),
                                    std::move(body), line, col);
@@ -42,6 +42,7 @@ AST::Ptr<AST::CompoundStmt> Parser::parseCompoundStatement() {
     consume(TokenType::LeftBrace, "expected '{'");
 
     auto compound = AST::make<AST::CompoundStmt>(line, col);
+    pushTypedefScope();
 
     while (!check(TokenType::RightBrace) && !isAtEnd()) {
         if (isDeclarationStart()) {
@@ -59,6 +60,7 @@ AST::Ptr<AST::CompoundStmt> Parser::parseCompoundStatement() {
         }
     }
 
+    popTypedefScope();
     consume(TokenType::RightBrace, "expected '}'");
     return compound;
 }
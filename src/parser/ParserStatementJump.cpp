@@ -36,6 +36,10 @@ AST::Ptr<AST::Statement> Parser::parseBreakStatement() {
     int line = previous().line;
     int col = previous().column;
 
+    if (loopDepth_ == 0 && switchDepth_ == 0) {
+        errorAtPosition(line, col, "'break' statement not in loop or switch statement");
+    }
+
     consume(TokenType::Semicolon, "expected ';' after break");
 
     return AST::make<AST::BreakStmt>(line, col);
@@ -47,6 +51,12 @@ AST::Ptr<AST::Statement> Parser::parseContinueStatement() {
     int line = previous().line;
     int col = previous().column;
 
+    // EN: A switch is not a continue target; only enclosing loops count.
+    // FR: Un switch n est pas une cible de continue; seules les boucles comptent.
+    if (loopDepth_ == 0) {
+        errorAtPosition(line, col, "'continue' statement not in loop statement");
+    }
+
     consume(TokenType::Semicolon, "expected ';' after continue");
 
     return AST::make<AST::ContinueStmt>(line, col);
@@ -62,6 +72,12 @@ AST::Ptr<AST::Statement> Parser::parseLabeledStatement() {
     advance();
     advance();
 
+    // EN: C89 requires a statement after a label; ';' is the usual workaround.
+    // FR: C89 exige une instruction apres un label; ';' est le contournement usuel.
+    if (check(TokenType::RightBrace) || isDeclarationStart()) {
+        error("label must be followed by a statement");
+    }
+
     auto stmt = parseStatement();
 
     return AST::make<AST::LabelStmt>(label, std::move(stmt), line, col);
@@ -27,13 +27,22 @@ AST::Ptr<AST::Type> Parser::parseAbstractDeclarator(AST::Ptr<AST::Type> baseType
     
     while (true) {
         if (match(TokenType::LeftBracket)) {
-            AST::Ptr<AST::Expression> size;
-            if (!check(TokenType::RightBracket)) {
-                size = parseConstantExpression();
+            // EN: Innermost dimension applies first, as in parseDirectDeclarator.
+            // FR: La dimension la plus interne s applique d abord, comme parseDirectDeclarator.
+            std::vector<AST::Ptr<AST::Expression>> sizes;
+            do {
+                AST::Ptr<AST::Expression> size;
+                if (!check(TokenType::RightBracket)) {
+                    size = parseConstantExpression();
+                }
+                consume(TokenType::RightBracket, "expected ']'");
+                sizes.push_back(std::move(size));
+            } while (match(TokenType::LeftBracket));
+
+            for (auto it = sizes.rbegin(); it != sizes.rend(); ++it) {
+                baseType = AST::make<AST::ArrayType>(std::move(baseType),
+                                                      std::move(*it), 0, 0);
             }
-            consume(TokenType::RightBracket, "expected ']'");
-            baseType = AST::make<AST::ArrayType>(std::move(baseType),
-                                                  std::move(size), 0, 0);
         } else if (match(TokenType::LeftParen)) {
             
             bool isVariadic = false;
@@ -0,0 +1,79 @@
+#include <parser/Parser.hpp>
+
+namespace cc1 {
+
+// EN: Checks whether a name currently denotes a typedef (inner bindings win).
+// FR: Verifie si un nom designe actuellement un typedef (les liaisons internes gagnent).
+bool Parser::isTypedefName(const std::string& name) const {
+    for (auto scope = typedefScopes_.rbegin(); scope != typedefScopes_.rend(); ++scope) {
+        auto it = scope->find(name);
+        if (it != scope->end()) {
+            return it->second != nullptr;
+        }
+    }
+    return false;
+}
+
+// EN: Returns a copy of the type a visible typedef name stands for.
+// FR: Renvoie une copie du type designe par un nom de typedef visible.
+AST::Ptr<AST::Type> Parser::typedefUnderlyingType(const std::string& name) const {
+    for (auto scope = typedefScopes_.rbegin(); scope != typedefScopes_.rend(); ++scope) {
+        auto it = scope->find(name);
+        if (it != scope->end()) {
+            return it->second ? it->second->clone() : nullptr;
+        }
+    }
+    return nullptr;
+}
+
+// EN: Binds a typedef name in the innermost scope.
+// FR: Lie un nom de typedef dans le scope le plus interne.
+void Parser::declareTypedefName(const std::string& name, const AST::Type* type) {
+    if (name.empty()) return;
+    if (typedefScopes_.empty()) pushTypedefScope();
+    typedefScopes_.back()[name] = type ? type->clone() : AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, 0, 0);
+}
+
+// EN: Binds __builtin_va_list: the SysV x86-64 __va_list_tag array, or a plain char* on i386.
+// FR: Lie __builtin_va_list: le tableau __va_list_tag SysV x86-64, ou un simple char* en i386.
+void Parser::declareBuiltinTypedefs(bool is64bit) {
+    if (!is64bit) {
+        auto charPtr = AST::make<AST::PointerType>(AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Char, 0, 0), 0, 0);
+        declareTypedefName("__builtin_va_list", charPtr.get());
+        return;
+    }
+    auto tag = AST::make<AST::StructType>("__va_list_tag", false, 0, 0);
+    tag->members.emplace_back("gp_offset", AST::make<AST::PrimitiveType>(AST::PrimitiveKind::UnsignedInt, 0, 0));
+    tag->members.emplace_back("fp_offset", AST::make<AST::PrimitiveType>(AST::PrimitiveKind::UnsignedInt, 0, 0));
+    tag->members.emplace_back("overflow_arg_area",
+                              AST::make<AST::PointerType>(AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Void, 0, 0), 0, 0));
+    tag->members.emplace_back("reg_save_area",
+                              AST::make<AST::PointerType>(AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Void, 0, 0), 0, 0));
+    tag->isComplete = true;
+    AST::ArrayType list(std::move(tag), 1LL, 0, 0);
+    declareTypedefName("__builtin_va_list", &list);
+}
+
+// EN: Binds an ordinary identifier, hiding outer typedefs of the same name.
+// FR: Lie un identifiant ordinaire, masquant les typedefs externes du meme nom.
+void Parser::declareOrdinaryName(const std::string& name) {
+    if (name.empty()) return;
+    if (typedefScopes_.empty()) pushTypedefScope();
+    typedefScopes_.back()[name] = nullptr;
+}
+
+// EN: Opens a block scope for typedef name bindings.
+// FR: Ouvre un scope de bloc pour les liaisons de noms typedef.
+void Parser::pushTypedefScope() {
+    typedefScopes_.emplace_back();
+}
+
+// EN: Closes the innermost block scope; the file scope is kept.
+// FR: Ferme le scope de bloc le plus interne; le scope fichier est conserve.
+void Parser::popTypedefScope() {
+    if (typedefScopes_.size() > 1) {
+        typedefScopes_.pop_back();
+    }
+}
+
+} 
@@ -69,6 +69,12 @@ void LineDirective::accept(DirectiveVisitor& visitor) {
     visitor.visit(*this);
 }
 
+// EN: Accepts a visitor for unknown directive handling.
+// FR: Accepte un visiteur pour traiter une directive inconnue.
+void UnknownDirective::accept(DirectiveVisitor& visitor) {
+    visitor.visit(*this);
+}
+
 } 
 } 
 
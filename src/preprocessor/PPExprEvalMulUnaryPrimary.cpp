@@ -1,4 +1,5 @@
 #include <preprocessor/PPExprEval.hpp>
+#include <utils/Escape.hpp>
 
 #include <cctype>
 
@@ -93,17 +94,7 @@ long long ExpressionEvaluator::evalPrimary(const std::string& expr, size_t& pos)
         long long val = 0;
         while (pos < expr.size() && expr[pos] != '\'') {
             if (expr[pos] == '\\' && pos + 1 < expr.size()) {
-                pos++;
-                switch (expr[pos]) {
-                    case 'n': val = '\n'; break;
-                    case 't': val = '\t'; break;
-                    case 'r': val = '\r'; break;
-                    case '0': val = '\0'; break;
-                    case '\\': val = '\\'; break;
-                    case '\'': val = '\''; break;
-                    default: val = expr[pos]; break;
-                }
-                pos++;
+                val = utils::decode_escape(expr, pos);
             } else {
                 val = static_cast<unsigned char>(expr[pos++]);
             }
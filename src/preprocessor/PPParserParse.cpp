@@ -17,6 +17,13 @@ std::unique_ptr<Directive> DirectiveParser::parse(const std::string& line) {
     }
     pos++;
 
+    // EN: GNU line markers ('# 12 "file" 2') from -E output act like #line.
+    // FR: Les marqueurs GNU ('# 12 "fichier" 2') issus de -E valent #line.
+    skipWhitespace(line, pos);
+    if (pos < line.size() && std::isdigit(static_cast<unsigned char>(line[pos]))) {
+        return parseLine(line, pos);
+    }
+
     std::string directive = parseIdentifier(line, pos);
 
     if (directive == "define") {
@@ -43,6 +50,8 @@ std::unique_ptr<Directive> DirectiveParser::parse(const std::string& line) {
         return parsePragma(line, pos);
     } else if (directive == "line") {
         return parseLine(line, pos);
+    } else if (!directive.empty()) {
+        return std::unique_ptr<Directive>(new UnknownDirective(directive));
     }
 
     return std::unique_ptr<Directive>();
@@ -198,6 +207,3 @@ std::unique_ptr<LineDirective> DirectiveParser::parseLine(const std::string& lin
 
 } 
 } 
-
-// TODO(cc1) EN: Add error reporting for unknown directives instead of silent ignore.
-// FR: Ajouter des erreurs pour directives inconnues au lieu d ignorer.
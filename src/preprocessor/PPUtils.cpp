@@ -14,10 +14,15 @@ std::string CommentRemover::remove(const std::string& source) {
     bool inBlockComment = false;
     bool inString = false;
     bool inChar = false;
+    int line = 1;
+    int commentLine = 0;
     
     for (size_t i = 0; i < source.size(); ++i) {
         char c = source[i];
         char next = (i + 1 < source.size()) ? source[i + 1] : '\0';
+        if (c == '\n') {
+            ++line;
+        }
         
         if (inLineComment) {
             if (c == '\n') {
@@ -70,12 +75,14 @@ std::string CommentRemover::remove(const std::string& source) {
             ++i;
         } else if (c == '/' && next == '*') {
             inBlockComment = true;
+            commentLine = line;
             ++i;
         } else {
             result += c;
         }
     }
     
+    unterminatedCommentLine_ = inBlockComment ? commentLine : 0;
     return result;
 }
 
@@ -93,6 +100,15 @@ std::string StringConcatenator::concatenate(const std::string& source) {
             continue;
         }
         
+        // EN: Line markers keep their filename string intact.
+        // FR: Les marqueurs de ligne gardent leur nom de fichier intact.
+        if (source[i] == '#' && (i == 0 || source[i - 1] == '\n')) {
+            while (i < source.size() && source[i] != '\n') {
+                result += source[i++];
+            }
+            continue;
+        }
+        
         
         if (source[i] == '"') {
             auto parsed = parseString(source, i);
@@ -168,6 +184,3 @@ std::pair<std::string, bool> StringConcatenator::parseString(const std::string&
 
 } 
 } 
-
-// TODO(cc1) EN: Improve handling of unterminated strings/comments with errors.
-// FR: Mieux gerer les chaines/commentaires non termines avec erreurs.
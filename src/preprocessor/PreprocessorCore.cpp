@@ -53,16 +53,38 @@ std::string Preprocessor::preprocess(const std::string& filename) {
     return preprocessString(source, filename);
 }
 
+// EN: Resolves a -include header like a quoted #include and preprocesses it.
+// FR: Resout un en-tete -include comme un #include entre guillemets et le pretraite.
+std::string Preprocessor::preprocessForcedInclude(const std::string& filename) {
+    std::string fullPath = fileHandler_->findInclude(filename, false);
+    if (fullPath.empty()) {
+        error("cannot find include file: " + filename);
+        return "";
+    }
+
+    return preprocess(fullPath);
+}
+
 // EN: Preprocesses raw text by removing comments, applying directives/macros,
 // and concatenating adjacent strings. FR: Pretraite un texte en supprimant les
 // commentaires, en appliquant directives/macros, puis en concatenant les chaines.
 std::string Preprocessor::preprocessString(const std::string& source, const std::string& filename) {
-    std::string processed = commentRemover_.remove(source);
+    std::string processed = removeComments(source, filename);
     processed = processSource(processed, filename);
     processed = stringConcatenator_.concatenate(processed);
     return processed;
 }
 
+// EN: Removes comments; a /* still open at end of file is reported where it starts.
+// FR: Retire les commentaires; un /* encore ouvert en fin de fichier est signale a son debut.
+std::string Preprocessor::removeComments(const std::string& source, const std::string& filename) {
+    std::string processed = commentRemover_.remove(source);
+    if (commentRemover_.unterminatedCommentLine() > 0) {
+        errorAt(filename, commentRemover_.unterminatedCommentLine(), "unterminated /* comment");
+    }
+    return processed;
+}
+
 // EN: Internal file preprocessing helper that skips diagnostic emission.
 // FR: Aide interne pour pretraiter un fichier sans emission de diagnostic.
 std::string Preprocessor::processFile(const std::string& filename) {
@@ -91,16 +113,38 @@ std::string Preprocessor::processSource(const std::string& source, const std::st
 
     macroTable_.define("__FILE__", pp::MacroDefinition("__FILE__", std::vector<std::string>(), "\"" + filename + "\"", false, false));
 
+    if (lineMarkers_) {
+        output += lineMarker(1, filename, prevOutput ? "1" : "");
+        lineMarkerPending_ = false;
+    }
+
     std::istringstream stream(source);
     std::string line;
 
+    // EN: Drops the carriage return of CRLF line endings so splices are detected.
+    // FR: Retire le retour chariot des fins de ligne CRLF pour detecter les raccords.
+    auto stripCarriageReturn = [](std::string& text) {
+        if (!text.empty() && text.back() == '\r') {
+            text.pop_back();
+        }
+    };
+
     while (std::getline(stream, line)) {
+        stripCarriageReturn(line);
+        int joinedLines = 0;
         while (!line.empty() && line.back() == '\\') {
             line.pop_back();
             std::string nextLine;
             if (std::getline(stream, nextLine)) {
+                stripCarriageReturn(nextLine);
                 line += nextLine;
                 currentLine_++;
+                joinedLines++;
+            } else {
+                // EN: C89 forbids a file ending in a line splice; there is no line to join.
+                // FR: C89 interdit un fichier finissant par un raccord; aucune ligne a joindre.
+                error("backslash-newline at end of file");
+                break;
             }
         }
 
@@ -108,8 +152,17 @@ std::string Preprocessor::processSource(const std::string& source, const std::st
 
         std::string processed = processLine(line);
         if (!processed.empty() || isActive()) {
+            if (lineMarkerPending_) {
+                output += lineMarker(currentLine_ - joinedLines, currentFile_, lineMarkerFlag_);
+                lineMarkerPending_ = false;
+            }
             output += processed;
             output += "\n";
+            if (joinedLines > 0) {
+                requestLineMarker("");
+            }
+        } else {
+            requestLineMarker("");
         }
 
         currentLine_++;
@@ -27,19 +27,38 @@ void Preprocessor::visit(pp::PragmaDirective& dir) {
 void Preprocessor::visit(pp::LineDirective& dir) {
     if (!isActive()) return;
 
-    currentLine_ = dir.lineNumber;
+    // EN: The number applies to the next line; the source loop increments after this one.
+    // FR: Le numero vaut pour la ligne suivante; la boucle incremente apres celle-ci.
+    currentLine_ = dir.lineNumber - 1;
     if (!dir.filename.empty()) {
         currentFile_ = dir.filename;
         fileHandler_->setCurrentFile(dir.filename);
         macroTable_.define("__FILE__", pp::MacroDefinition("__FILE__", std::vector<std::string>(), "\"" + dir.filename + "\"", false, false));
     }
+    requestLineMarker("");
+}
+
+// EN: Ignores legacy directives such as #assert/#unassert with a warning; skipped
+//     branches may contain anything and stay silent.
+// FR: Ignore les directives anciennes comme #assert/#unassert avec un avertissement;
+//     les branches sautees peuvent tout contenir et restent silencieuses.
+void Preprocessor::visit(pp::UnknownDirective& dir) {
+    if (!isActive()) return;
+
+    warning("ignoring unknown directive #" + dir.name);
 }
 
 // EN: Records an error and prints it with the current logical location.
 // FR: Enregistre une erreur et l affiche avec la position logique courante.
 void Preprocessor::error(const std::string& message) {
+    errorAt(currentFile_, currentLine_, message);
+}
+
+// EN: Records an error and prints it at the given file and line.
+// FR: Enregistre une erreur et l affiche au fichier et a la ligne donnes.
+void Preprocessor::errorAt(const std::string& file, int line, const std::string& message) {
     hadError_ = true;
-    std::cerr << currentFile_ << ":" << currentLine_ << ": error: " << message << "\n";
+    std::cerr << file << ":" << line << ": error: " << message << "\n";
 }
 
 // EN: Prints a warning with the current logical location.
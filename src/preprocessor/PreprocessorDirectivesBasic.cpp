@@ -40,12 +40,13 @@ void Preprocessor::visit(pp::IncludeDirective& dir) {
         return;
     }
 
-    content = commentRemover_.remove(content);
+    content = removeComments(content, fullPath);
     std::string processed = processSource(content, fullPath);
 
     if (currentOutput_) {
         *currentOutput_ += processed;
     }
+    requestLineMarker("2");
 }
 
 // TODO(cc1) EN: Track include stack depth to detect recursive includes earlier.
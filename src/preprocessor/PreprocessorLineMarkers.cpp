@@ -0,0 +1,26 @@
+#include <preprocessor/Preprocessor.hpp>
+
+namespace cc1 {
+
+// EN: Builds a GNU line marker; flag "1" enters a file, "2" returns to one.
+// FR: Construit un marqueur GNU; flag "1" entre dans un fichier, "2" y revient.
+std::string Preprocessor::lineMarker(int line, const std::string& filename, const std::string& flag) const {
+    std::string marker = "# " + std::to_string(line) + " \"" + filename + "\"";
+    if (!flag.empty()) {
+        marker += " " + flag;
+    }
+    return marker + "\n";
+}
+
+// EN: Remembers that output drifted from the source; a returning include
+// flag wins over a plain resync. FR: Note que la sortie a derive de la source;
+// le flag de retour d include l emporte sur une resynchro simple.
+void Preprocessor::requestLineMarker(const std::string& flag) {
+    if (!lineMarkers_) return;
+    if (!lineMarkerPending_ || !flag.empty()) {
+        lineMarkerFlag_ = flag;
+    }
+    lineMarkerPending_ = true;
+}
+
+} 
@@ -20,6 +20,23 @@ bool SemanticAnalyzer::evaluateConstantExpr(AST::Expression* expr, long long& re
     return v.ok;
 }
 
+// EN: Checks for a null pointer constant: an integer constant 0, optionally cast to void*.
+// FR: Verifie une constante pointeur nul : une constante entiere 0, eventuellement castee en void*.
+bool SemanticAnalyzer::isNullPointerConstant(AST::Expression* expr) {
+    if (!expr) return false;
+    if (auto* cast = dynamic_cast<AST::CastExpr*>(expr)) {
+        auto* ptr = dynamic_cast<AST::PointerType*>(stripQualifiers(cast->targetType.get()));
+        if (ptr && isVoidType(ptr->pointee.get())) {
+            return isNullPointerConstant(cast->operand.get());
+        }
+    }
+
+    AST::Ptr<AST::Type> type = getExprType(expr);
+    if (!type || !isIntegerType(type.get())) return false;
+    long long value = 0;
+    return evaluateConstantExpr(expr, value) && value == 0;
+}
+
 // EN: Detects division by zero in constant-expression subtrees.
 // FR: Detecte les divisions par zero dans une expression constante.
 bool SemanticAnalyzer::hasDivisionByZero(AST::Expression* expr) {
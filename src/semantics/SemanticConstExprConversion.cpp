@@ -0,0 +1,73 @@
+#include <semantics/SemanticAnalyzer.hpp>
+
+namespace cc1 {
+
+// EN: Warns when a folded integer constant changes value once converted to destType.
+// FR: Avertit quand une constante entiere evaluee change de valeur une fois convertie.
+void SemanticAnalyzer::checkConstantConversion(AST::Type* destType, AST::Expression* expr, int line, int column) {
+    auto* prim = dynamic_cast<AST::PrimitiveType*>(stripQualifiers(destType));
+    if (!prim || !isIntegerType(prim)) return;
+
+    long long value;
+    if (!evaluateConstantExpr(expr, value)) return;
+
+    int bits = 32;
+    bool isUnsigned = false;
+    switch (prim->kind) {
+        case AST::PrimitiveKind::UnsignedChar:
+            isUnsigned = true;
+            bits = 8;
+            break;
+        case AST::PrimitiveKind::Char:
+        case AST::PrimitiveKind::SignedChar:
+            bits = 8;
+            break;
+        case AST::PrimitiveKind::UnsignedShort:
+            isUnsigned = true;
+            bits = 16;
+            break;
+        case AST::PrimitiveKind::Short:
+            bits = 16;
+            break;
+        case AST::PrimitiveKind::UnsignedInt:
+            isUnsigned = true;
+            break;
+        case AST::PrimitiveKind::UnsignedLong:
+            isUnsigned = true;
+            bits = is64bit_ ? 64 : 32;
+            break;
+        case AST::PrimitiveKind::Long:
+            bits = is64bit_ ? 64 : 32;
+            break;
+        case AST::PrimitiveKind::LongLong:
+        case AST::PrimitiveKind::UnsignedLongLong:
+            bits = 64;
+            break;
+        default:
+            break;
+    }
+    if (bits >= 64) return;
+
+    long long minSigned = -(1LL << (bits - 1));
+    long long maxSigned = (1LL << (bits - 1)) - 1;
+    long long maxUnsigned = (1LL << bits) - 1;
+
+    // EN: Sign flips (negative to unsigned, unsigned int constants to int) belong to -Wsign-conversion.
+    // FR: Les changements de signe (negatif vers non signe, constantes unsigned vers int) relevent de -Wsign-conversion.
+    long long upper = (isUnsigned || bits >= 32) ? maxUnsigned : maxSigned;
+    if (value >= minSigned && value <= upper) return;
+
+    unsigned long long mask = (1ULL << bits) - 1ULL;
+    unsigned long long truncated = static_cast<unsigned long long>(value) & mask;
+    long long converted = static_cast<long long>(truncated);
+    if (!isUnsigned && (truncated >> (bits - 1)) & 1ULL) {
+        converted = static_cast<long long>(truncated | ~mask);
+    }
+    if (converted == value) return;
+
+    warning(line, column,
+            "implicit conversion changes value from " + std::to_string(value) + " to " +
+                std::to_string(converted) + " [-Wconstant-conversion]");
+}
+
+} 
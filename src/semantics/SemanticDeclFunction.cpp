@@ -23,6 +23,10 @@ void SemanticAnalyzer::visit(AST::FunctionDecl& node) {
 
     currentScope_->define(node.name, sym, std::move(funcType));
 
+    if (node.body && node.name == "main") {
+        checkMainSignature(node);
+    }
+
     if (node.body) {
         currentFunction_ = &node;
         inGlobalScope_ = false;
@@ -42,6 +46,65 @@ void SemanticAnalyzer::visit(AST::FunctionDecl& node) {
     }
 }
 
+// EN: Accepts the two hosted forms (plus an envp third argument); anything else only warns.
+// FR: Accepte les deux formes hebergees (plus un troisieme argument envp); le reste avertit seulement.
+void SemanticAnalyzer::checkMainSignature(AST::FunctionDecl& node) {
+    // EN: Strips qualifiers and typedef wrappers captured by the parser.
+    // FR: Retire les qualifiers et les typedefs captures par le parseur.
+    auto stripAll = [this](AST::Type* type) {
+        type = stripQualifiers(type);
+        while (auto* td = dynamic_cast<AST::TypedefType*>(type)) {
+            if (!td->underlyingType) break;
+            type = stripQualifiers(td->underlyingType.get());
+        }
+        return type;
+    };
+    // EN: Matches 'char **' and its array spelling 'char *[]'.
+    // FR: Reconnait 'char **' et son ecriture tableau 'char *[]'.
+    auto isCharPtrPtr = [&](AST::Type* type) {
+        type = stripAll(type);
+        AST::Type* inner = nullptr;
+        if (auto* ptr = dynamic_cast<AST::PointerType*>(type)) {
+            inner = ptr->pointee.get();
+        } else if (auto* arr = dynamic_cast<AST::ArrayType*>(type)) {
+            inner = arr->elementType.get();
+        }
+        auto* innerPtr = dynamic_cast<AST::PointerType*>(stripAll(inner));
+        auto* prim = innerPtr ? dynamic_cast<AST::PrimitiveType*>(stripAll(innerPtr->pointee.get())) : nullptr;
+        return prim && prim->kind == AST::PrimitiveKind::Char;
+    };
+    auto isPlainInt = [&](AST::Type* type) {
+        auto* prim = dynamic_cast<AST::PrimitiveType*>(stripAll(type));
+        return prim && prim->kind == AST::PrimitiveKind::Int;
+    };
+
+    if (!isPlainInt(node.returnType.get())) {
+        warning(node.line, node.column, "return type of 'main' is not 'int'");
+    }
+
+    std::vector<AST::ParamDecl*> params;
+    for (auto& param : node.parameters) {
+        if (param) params.push_back(param.get());
+    }
+    if (params.size() == 1 && isVoidType(stripAll(params[0]->type.get()))) {
+        params.clear();
+    }
+
+    if (params.size() == 1 || params.size() > 3) {
+        warning(node.line, node.column, "'main' takes only zero or two arguments");
+        return;
+    }
+    if (!params.empty() && !isPlainInt(params[0]->type.get())) {
+        warning(params[0]->line, params[0]->column, "first argument of 'main' should be 'int'");
+    }
+    if (params.size() >= 2 && !isCharPtrPtr(params[1]->type.get())) {
+        warning(params[1]->line, params[1]->column, "second argument of 'main' should be 'char **'");
+    }
+    if (params.size() == 3 && !isCharPtrPtr(params[2]->type.get())) {
+        warning(params[2]->line, params[2]->column, "third argument of 'main' should probably be 'char **'");
+    }
+}
+
 } 
 
 // TODO(cc1) EN: Diagnose conflicting function prototypes across scopes.
@@ -6,6 +6,23 @@ namespace cc1 {
 // FR: Visite l unite de traduction et analyse toutes les declarations globales.
 void SemanticAnalyzer::visit(AST::TranslationUnit& node) {
     inGlobalScope_ = true;
+
+    // EN: Pre-registers file-scope struct/union definitions so sizeof can see later tags.
+    // FR: Pre-enregistre les definitions struct/union globales pour que sizeof voie les tags suivants.
+    for (auto& decl : node.declarations) {
+        auto* structDecl = dynamic_cast<AST::StructDecl*>(decl.get());
+        if (!structDecl || structDecl->name.empty() || structDecl->members.empty()) continue;
+        if (currentScope_->lookupTag(structDecl->name)) continue;
+
+        Symbol sym;
+        sym.name = structDecl->name;
+        sym.tagKind = structDecl->isUnion ? Symbol::TagKind::Union : Symbol::TagKind::Struct;
+        sym.line = structDecl->line;
+        sym.column = structDecl->column;
+        sym.structDecl = structDecl;
+        currentScope_->defineTag(structDecl->name, sym);
+    }
+
     for (auto& decl : node.declarations) {
         if (decl) decl->accept(*this);
     }
@@ -42,6 +42,22 @@ void SemanticAnalyzer::visit(AST::VarDecl& node) {
                     "' leaves the object uninitialized [-Wdefault-const-init-var-unsafe]");
     }
 
+    if (auto* arrType = dynamic_cast<AST::ArrayType*>(stripQualifiers(node.type.get()))) {
+        auto* strLit = dynamic_cast<AST::StringLiteral*>(node.initializer.get());
+        if (strLit && arrType->size < 0 && !arrType->sizeExpr) {
+            // EN: `char s[] = "..."` takes the decoded literal length plus the terminator.
+            // FR: `char s[] = "..."` prend la longueur decodee du litteral plus le terminateur.
+            arrType->size = static_cast<long long>(strLit->value.length() + 1);
+        }
+        long long constSize = 0;
+        if (arrType->size < 0 && arrType->sizeExpr &&
+            evaluateConstantExpr(arrType->sizeExpr.get(), constSize) && constSize > 0) {
+            // EN: Folds constant bounds (enumerators, sizeof) so cloned types keep the length.
+            // FR: Replie les bornes constantes (enumerateurs, sizeof) pour que les clones gardent la longueur.
+            arrType->size = constSize;
+        }
+    }
+
     if (node.initializer) {
         node.initializer->accept(*this);
         AST::Ptr<AST::Type> initType = getExprType(node.initializer.get());
@@ -50,7 +66,31 @@ void SemanticAnalyzer::visit(AST::VarDecl& node) {
             AST::Type* destType = stripQualifiers(node.type.get());
             AST::Type* srcType = stripQualifiers(initType.get());
 
-            if (isIntegerType(destType) && (isPointerType(srcType) || isArrayType(srcType))) {
+            // EN: Strips typedef wrappers so 'T a = b;' sees the struct behind T.
+            // FR: Retire les typedefs pour que 'T a = b;' voie la struct derriere T.
+            auto stripAll = [this](AST::Type* type) {
+                while (auto* td = dynamic_cast<AST::TypedefType*>(type)) {
+                    if (!td->underlyingType) break;
+                    type = stripQualifiers(td->underlyingType.get());
+                }
+                return type;
+            };
+            auto* destStruct = dynamic_cast<AST::StructType*>(stripAll(destType));
+            auto* srcStruct = dynamic_cast<AST::StructType*>(stripAll(srcType));
+            bool structMismatch = false;
+            if (destStruct && srcStruct) {
+                structMismatch = !destStruct->name.empty() && !srcStruct->name.empty() &&
+                                 !typesCompatible(destStruct, srcStruct);
+            } else if (destStruct || srcStruct) {
+                AST::Type* other = destStruct ? stripAll(srcType) : stripAll(destType);
+                structMismatch = isScalarType(other) || isVoidType(other);
+            }
+
+            if (structMismatch) {
+                error(node.line, node.column,
+                      "initializing '" + typeToString(node.type.get()) +
+                          "' with an expression of incompatible type '" + typeToString(initType.get()) + "'");
+            } else if (isIntegerType(destType) && (isPointerType(srcType) || isArrayType(srcType))) {
                 std::string initTypeStr;
                 if (auto* strLit = dynamic_cast<AST::StringLiteral*>(node.initializer.get())) {
                     initTypeStr = "char[" + std::to_string(strLit->value.length() + 1) + "]";
@@ -61,7 +101,8 @@ void SemanticAnalyzer::visit(AST::VarDecl& node) {
                       "incompatible pointer to integer conversion initializing '" +
                           typeToString(node.type.get()) + "' with an expression of type '" +
                           initTypeStr + "' [-Wint-conversion]");
-            } else if (isPointerType(destType) && isIntegerType(srcType)) {
+            } else if (isPointerType(destType) && isIntegerType(srcType) &&
+                       !isNullPointerConstant(node.initializer.get())) {
                 std::string suggestion;
                 if (dynamic_cast<AST::UnaryExpr*>(node.initializer.get())) {
                     auto* unary = dynamic_cast<AST::UnaryExpr*>(node.initializer.get());
@@ -73,6 +114,8 @@ void SemanticAnalyzer::visit(AST::VarDecl& node) {
                       "incompatible integer to pointer conversion initializing '" +
                           typeToString(node.type.get()) + "' with an expression of type '" +
                           typeToString(srcType) + "'" + suggestion + " [-Wint-conversion]");
+            } else if (isIntegerType(destType) && isIntegerType(srcType)) {
+                checkConstantConversion(destType, node.initializer.get(), node.line, node.column);
             }
         }
     }
@@ -1,5 +1,7 @@
 #include <semantics/SemanticAnalyzer.hpp>
 
+#include <algorithm>
+
 namespace cc1 {
 
 // EN: Stores a resolved type on the expression for later passes.
@@ -8,10 +10,34 @@ static void setResolvedExprType(AST::Expression& expr, const AST::Ptr<AST::Type>
     expr.resolvedType = type ? type->clone() : nullptr;
 }
 
-// EN: Assigns integer literal type (default int).
-// FR: Assigne le type d un literal entier (int par defaut).
+// EN: Unsigned suffixes select the unsigned kinds; an unsuffixed hex/octal constant that
+//     only fits in unsigned int becomes unsigned int. Other literals stay int.
+// FR: Les suffixes non signes choisissent les types non signes; une constante hex/octale
+//     sans suffixe qui ne tient que dans unsigned int devient unsigned int. Sinon int.
+AST::PrimitiveKind SemanticAnalyzer::integerLiteralKind(const AST::IntegerLiteral& lit) const {
+    std::string suffix;
+    for (char ch : lit.text) {
+        if (ch == 'u' || ch == 'U' || ch == 'l' || ch == 'L') {
+            suffix += static_cast<char>(ch == 'U' ? 'u' : (ch == 'L' ? 'l' : ch));
+        }
+    }
+    if (suffix.find('u') != std::string::npos) {
+        size_t longCount = std::count(suffix.begin(), suffix.end(), 'l');
+        if (longCount >= 2) return AST::PrimitiveKind::UnsignedLongLong;
+        if (longCount == 1) return AST::PrimitiveKind::UnsignedLong;
+        return AST::PrimitiveKind::UnsignedInt;
+    }
+    bool hexOrOctal = lit.text.size() > 1 && lit.text[0] == '0';
+    if (suffix.empty() && hexOrOctal && lit.value > 0x7FFFFFFFLL && lit.value <= 0xFFFFFFFFLL) {
+        return AST::PrimitiveKind::UnsignedInt;
+    }
+    return AST::PrimitiveKind::Int;
+}
+
+// EN: Assigns integer literal type (int unless its suffix or radix says otherwise).
+// FR: Assigne le type d un literal entier (int sauf suffixe ou base contraire).
 void SemanticAnalyzer::visit(AST::IntegerLiteral& node) {
-    exprTypes_[&node] = AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, node.line, node.column);
+    exprTypes_[&node] = AST::make<AST::PrimitiveType>(integerLiteralKind(node), node.line, node.column);
     setResolvedExprType(node, exprTypes_[&node]);
 }
 
@@ -51,6 +77,8 @@ void SemanticAnalyzer::visit(AST::Identifier& node) {
 // EN: Assigns sizeof expression type (unsigned long).
 // FR: Assigne le type d un sizeof (unsigned long).
 void SemanticAnalyzer::visit(AST::SizeofExpr& node) {
+    if (node.operand) node.operand->accept(*this);
+
     exprTypes_[&node] = AST::make<AST::PrimitiveType>(AST::PrimitiveKind::UnsignedLong, node.line, node.column);
     setResolvedExprType(node, exprTypes_[&node]);
 }
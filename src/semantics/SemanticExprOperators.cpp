@@ -17,6 +17,13 @@ void SemanticAnalyzer::visit(AST::BinaryExpr& node) {
     AST::Ptr<AST::Type> leftType = getExprType(node.left.get());
     AST::Ptr<AST::Type> rightType = getExprType(node.right.get());
 
+    bool isAssignment = node.op == AST::BinaryOp::Assign ||
+                        (node.op >= AST::BinaryOp::AddAssign && node.op <= AST::BinaryOp::RightShiftAssign);
+    if (isAssignment && !isLvalueExpression(node.left.get())) {
+        error(node.left->line, node.left->column, "expression is not assignable");
+        return;
+    }
+
     if (node.op == AST::BinaryOp::Assign) {
         if (auto* id = dynamic_cast<AST::Identifier*>(node.left.get())) {
             Symbol* sym = currentScope_->lookup(id->name);
@@ -31,20 +38,44 @@ void SemanticAnalyzer::visit(AST::BinaryExpr& node) {
         if (leftType && rightType) {
             if (!canAssign(leftType.get(), rightType.get())) {
                 if (isPointerType(leftType.get()) && isIntegerType(rightType.get())) {
-                    error(node.line, node.column,
-                          "incompatible integer to pointer conversion assigning to '" +
-                          typeToString(leftType.get()) + "' from '" +
-                          typeToString(rightType.get()) + "' [-Wint-conversion]");
+                    if (!isNullPointerConstant(node.right.get())) {
+                        error(node.line, node.column,
+                              "incompatible integer to pointer conversion assigning to '" +
+                              typeToString(leftType.get()) + "' from '" +
+                              typeToString(rightType.get()) + "' [-Wint-conversion]");
+                    }
                 } else if (isIntegerType(leftType.get()) && isPointerType(rightType.get())) {
                     error(node.line, node.column,
                           "incompatible pointer to integer conversion assigning to '" +
                           typeToString(leftType.get()) + "' from '" +
                           typeToString(rightType.get()) + "' [-Wint-conversion]");
                 }
+            } else if (isIntegerType(leftType.get()) && isIntegerType(rightType.get())) {
+                checkConstantConversion(leftType.get(), node.right.get(), node.line, node.column);
             }
         }
     }
 
+    switch (node.op) {
+        case AST::BinaryOp::Equal:
+        case AST::BinaryOp::NotEqual:
+        case AST::BinaryOp::Less:
+        case AST::BinaryOp::LessEqual:
+        case AST::BinaryOp::Greater:
+        case AST::BinaryOp::GreaterEqual:
+        case AST::BinaryOp::LogicalAnd:
+        case AST::BinaryOp::LogicalOr:
+            // EN: Comparisons and logical operators always yield int.
+            // FR: Les comparaisons et operateurs logiques donnent toujours int.
+            leftType = AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, node.line, node.column);
+            break;
+        case AST::BinaryOp::Comma:
+            leftType = std::move(rightType);
+            break;
+        default:
+            break;
+    }
+
     if (leftType) {
         exprTypes_[&node] = std::move(leftType);
         setResolvedExprType(node, exprTypes_[&node]);
@@ -80,6 +111,12 @@ void SemanticAnalyzer::visit(AST::UnaryExpr& node) {
                 }
             }
             break;
+        case AST::UnaryOp::LogicalNot:
+            // EN: '!' always yields int 0/1, whatever the operand type.
+            // FR: '!' donne toujours un int 0/1, quel que soit le type de l operande.
+            exprTypes_[&node] = AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, node.line, node.column);
+            setResolvedExprType(node, exprTypes_[&node]);
+            break;
         default:
             if (operandType) {
                 exprTypes_[&node] = std::move(operandType);
@@ -100,6 +137,17 @@ void SemanticAnalyzer::visit(AST::CastExpr& node) {
     }
 }
 
+// EN: Types va_arg as its requested type; typedef wrappers are kept for codegen to resolve.
+// FR: Type va_arg comme le type demande; les typedefs sont gardes pour le codegen.
+void SemanticAnalyzer::visit(AST::VaArgExpr& node) {
+    if (node.list) node.list->accept(*this);
+
+    if (node.targetType) {
+        exprTypes_[&node] = node.targetType->clone();
+        setResolvedExprType(node, exprTypes_[&node]);
+    }
+}
+
 // EN: Validates call arguments and sets the call result type.
 // FR: Valide les arguments d appel et fixe le type resultat.
 void SemanticAnalyzer::visit(AST::CallExpr& node) {
@@ -133,7 +181,8 @@ void SemanticAnalyzer::visit(AST::CallExpr& node) {
                                       "incompatible pointer to integer conversion passing '" +
                                       argTypeStr + "' to parameter of type '" +
                                       typeToString(paramType) + "' [-Wint-conversion]");
-                            } else if (isPointerType(paramType) && isIntegerType(argType.get())) {
+                            } else if (isPointerType(paramType) && isIntegerType(argType.get()) &&
+                                       !isNullPointerConstant(node.arguments[i].get())) {
                                 error(node.arguments[i]->line, node.arguments[i]->column,
                                       "incompatible integer to pointer conversion passing '" +
                                       typeToString(argType.get()) + "' to parameter of type '" +
@@ -152,10 +201,50 @@ void SemanticAnalyzer::visit(AST::CallExpr& node) {
     }
 }
 
-// EN: Visits member access; type resolution handled elsewhere.
-// FR: Visite l acces membre; la resolution se fait ailleurs.
+// EN: Resolves the member type of `s.m` / `p->m` so `&s.m`, sizeof and calls see it.
+// FR: Resout le type du membre de `s.m` / `p->m` pour que `&s.m`, sizeof et les appels le voient.
 void SemanticAnalyzer::visit(AST::MemberExpr& node) {
     if (node.object) node.object->accept(*this);
+
+    AST::Ptr<AST::Type> objectType = getExprType(node.object.get());
+    // EN: Strips qualifiers and typedef wrappers captured by the parser.
+    // FR: Retire les qualifiers et les typedefs captures par le parseur.
+    auto stripAll = [this](AST::Type* type) {
+        type = stripQualifiers(type);
+        while (auto* td = dynamic_cast<AST::TypedefType*>(type)) {
+            if (!td->underlyingType) break;
+            type = stripQualifiers(td->underlyingType.get());
+        }
+        return type;
+    };
+
+    AST::Type* base = stripAll(objectType.get());
+    if (node.isArrow) {
+        if (auto* ptr = dynamic_cast<AST::PointerType*>(base)) {
+            base = stripAll(ptr->pointee.get());
+        } else if (auto* arr = dynamic_cast<AST::ArrayType*>(base)) {
+            base = stripAll(arr->elementType.get());
+        } else {
+            base = nullptr;
+        }
+    }
+
+    auto* structType = dynamic_cast<AST::StructType*>(base);
+    if (structType && structType->members.empty() && !structType->name.empty()) {
+        Symbol* tag = currentScope_->lookupTag(structType->name);
+        if (tag && tag->structDecl && tag->structDecl->declaredType) {
+            structType = tag->structDecl->declaredType.get();
+        }
+    }
+    if (!structType) return;
+
+    for (const auto& member : structType->members) {
+        if (member.name == node.member && member.type) {
+            exprTypes_[&node] = member.type->clone();
+            setResolvedExprType(node, exprTypes_[&node]);
+            return;
+        }
+    }
 }
 
 // EN: Validates array indexing and infers element type.
@@ -169,8 +258,10 @@ void SemanticAnalyzer::visit(AST::IndexExpr& node) {
         AST::Type* base = stripQualifiers(arrayType.get());
         if (auto* arr = dynamic_cast<AST::ArrayType*>(base)) {
             exprTypes_[&node] = arr->elementType->clone();
+            setResolvedExprType(node, exprTypes_[&node]);
         } else if (auto* ptr = dynamic_cast<AST::PointerType*>(base)) {
             exprTypes_[&node] = ptr->pointee->clone();
+            setResolvedExprType(node, exprTypes_[&node]);
         }
     }
 }
@@ -183,9 +274,61 @@ void SemanticAnalyzer::visit(AST::TernaryExpr& node) {
     if (node.elseExpr) node.elseExpr->accept(*this);
 
     AST::Ptr<AST::Type> thenType = getExprType(node.thenExpr.get());
-    if (thenType) {
-        exprTypes_[&node] = std::move(thenType);
+    AST::Ptr<AST::Type> elseType = getExprType(node.elseExpr.get());
+    if (!thenType || !elseType) {
+        if (thenType) {
+            exprTypes_[&node] = std::move(thenType);
+            setResolvedExprType(node, exprTypes_[&node]);
+        }
+        return;
+    }
+
+    AST::Type* thenBase = stripQualifiers(thenType.get());
+    AST::Type* elseBase = stripQualifiers(elseType.get());
+    bool thenArith = isIntegerType(thenBase) || isFloatType(thenBase);
+    bool elseArith = isIntegerType(elseBase) || isFloatType(elseBase);
+
+    AST::Ptr<AST::Type> resultType;
+    if (thenArith && elseArith) {
+        resultType = commonArithmeticType(thenBase, elseBase);
+    } else if (isStructType(thenBase) || isStructType(elseBase)) {
+        // EN: Aggregate arms must name the same struct or union type (unnamed tags compare by shape).
+        // FR: Les branches agregats doivent designer le meme type struct ou union (tags anonymes par forme).
+        bool mismatch = false;
+        if (isStructType(thenBase) && isStructType(elseBase)) {
+            auto* thenStruct = dynamic_cast<AST::StructType*>(thenBase);
+            auto* elseStruct = dynamic_cast<AST::StructType*>(elseBase);
+            mismatch = !thenStruct->name.empty() && !elseStruct->name.empty() && !typesCompatible(thenBase, elseBase);
+        } else {
+            AST::Type* other = isStructType(thenBase) ? elseBase : thenBase;
+            mismatch = isScalarType(other) || isVoidType(other);
+        }
+        if (mismatch) {
+            error(node.line, node.column,
+                  "incompatible operand types ('" + typeToString(thenType.get()) + "' and '" +
+                      typeToString(elseType.get()) + "')");
+            return;
+        }
+        resultType = std::move(thenType);
+    } else if (isVoidType(thenBase) || isVoidType(elseBase)) {
+        resultType = AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Void, node.line, node.column);
+    } else if ((isPointerType(elseBase) || isArrayType(elseBase) || dynamic_cast<AST::FunctionType*>(elseBase)) &&
+               isIntegerType(thenBase)) {
+        resultType = std::move(elseType);
+    } else {
+        resultType = std::move(thenType);
     }
+
+    // EN: Array arms decay to pointers to their element type, function arms to function pointers.
+    // FR: Les branches tableau se degradent en pointeurs sur leur type d element, les fonctions en pointeurs de fonction.
+    if (auto* arrType = dynamic_cast<AST::ArrayType*>(stripQualifiers(resultType.get()))) {
+        resultType = AST::make<AST::PointerType>(arrType->elementType->clone(), node.line, node.column);
+    } else if (dynamic_cast<AST::FunctionType*>(stripQualifiers(resultType.get()))) {
+        resultType = AST::make<AST::PointerType>(std::move(resultType), node.line, node.column);
+    }
+
+    exprTypes_[&node] = std::move(resultType);
+    setResolvedExprType(node, exprTypes_[&node]);
 }
 
 } 
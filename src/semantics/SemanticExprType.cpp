@@ -13,7 +13,7 @@ AST::Ptr<AST::Type> SemanticAnalyzer::getExprType(AST::Expression* expr) {
     }
 
     if (auto* lit = dynamic_cast<AST::IntegerLiteral*>(expr)) {
-        return AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, lit->line, lit->column);
+        return AST::make<AST::PrimitiveType>(integerLiteralKind(*lit), lit->line, lit->column);
     }
 
     if (auto* lit = dynamic_cast<AST::CharLiteral*>(expr)) {
@@ -77,4 +77,22 @@ AST::Ptr<AST::Type> SemanticAnalyzer::getExprType(AST::Expression* expr) {
     return nullptr;
 }
 
+// EN: Objects are named variables, subscripts, members, dereferences and string literals;
+//     every other expression (?:, casts, calls, arithmetic, ++x, enumerators) is a value.
+// FR: Les objets sont les variables nommees, indexations, membres, dereferencements et chaines;
+//     toute autre expression (?:, casts, appels, arithmetique, ++x, enumerateurs) est une valeur.
+bool SemanticAnalyzer::isLvalueExpression(AST::Expression* expr) {
+    if (!expr) return false;
+
+    if (auto* id = dynamic_cast<AST::Identifier*>(expr)) {
+        Symbol* sym = currentScope_->lookup(id->name);
+        return !sym || (!sym->isEnumConstant && !sym->isFunction);
+    }
+    if (auto* unary = dynamic_cast<AST::UnaryExpr*>(expr)) {
+        return unary->op == AST::UnaryOp::Dereference;
+    }
+    return dynamic_cast<AST::IndexExpr*>(expr) || dynamic_cast<AST::MemberExpr*>(expr) ||
+           dynamic_cast<AST::StringLiteral*>(expr);
+}
+
 } 
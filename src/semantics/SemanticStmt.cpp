@@ -6,7 +6,10 @@ namespace cc1 {
 // FR: Analyse un bloc compose, en creant un scope si besoin.
 void SemanticAnalyzer::visit(AST::CompoundStmt& node) {
     
-    bool needsScope = currentFunction_ == nullptr || !currentScope_->isFunction;
+    // EN: Only the function body shares the parameter scope; nested blocks get their own.
+    // FR: Seul le corps de fonction partage le scope des parametres; les blocs imbriques ont le leur.
+    bool isFunctionBody = currentFunction_ && currentScope_->isFunction && currentFunction_->body.get() == &node;
+    bool needsScope = !isFunctionBody;
     
     if (needsScope) {
         enterScope(false);
@@ -68,12 +71,23 @@ void SemanticAnalyzer::visit(AST::ForStmt& node) {
 // FR: Analyse une instruction switch.
 void SemanticAnalyzer::visit(AST::SwitchStmt& node) {
     if (node.expression) node.expression->accept(*this);
+
+    // EN: The body may be any statement; without a case or default label none of it can run.
+    // FR: Le corps peut etre toute instruction; sans label case ni default rien ne s execute.
+    switchLabelCounts_.push_back(0);
     if (node.body) node.body->accept(*this);
+    int labels = switchLabelCounts_.back();
+    switchLabelCounts_.pop_back();
+
+    if (labels == 0) {
+        warning(node.line, node.column, "switch statement has no cases");
+    }
 }
 
 // EN: Analyzes a case label and its statement body.
 // FR: Analyse un label case et son corps.
 void SemanticAnalyzer::visit(AST::CaseStmt& node) {
+    if (!switchLabelCounts_.empty()) ++switchLabelCounts_.back();
     if (node.value) node.value->accept(*this);
     if (node.body) node.body->accept(*this);
 }
@@ -81,6 +95,7 @@ void SemanticAnalyzer::visit(AST::CaseStmt& node) {
 // EN: Analyzes a default label and its body.
 // FR: Analyse un label default et son corps.
 void SemanticAnalyzer::visit(AST::DefaultStmt& node) {
+    if (!switchLabelCounts_.empty()) ++switchLabelCounts_.back();
     if (node.body) node.body->accept(*this);
 }
 
@@ -88,6 +103,29 @@ void SemanticAnalyzer::visit(AST::DefaultStmt& node) {
 // FR: Analyse l expression d un return.
 void SemanticAnalyzer::visit(AST::ReturnStmt& node) {
     if (node.value) node.value->accept(*this);
+
+    if (!node.value || !currentFunction_) return;
+
+    AST::Ptr<AST::Type> valueType = getExprType(node.value.get());
+    if (!valueType) return;
+
+    AST::Type* destType = stripQualifiers(currentFunction_->returnType.get());
+    AST::Type* srcType = stripQualifiers(valueType.get());
+
+    if (isIntegerType(destType) && (isPointerType(srcType) || isArrayType(srcType))) {
+        error(node.line, node.column,
+              "incompatible pointer to integer conversion returning '" + typeToString(srcType) +
+                  "' from a function with result type '" + typeToString(currentFunction_->returnType.get()) +
+                  "' [-Wint-conversion]");
+    } else if (isPointerType(destType) && isIntegerType(srcType)) {
+        if (isNullPointerConstant(node.value.get())) return;
+        error(node.line, node.column,
+              "incompatible integer to pointer conversion returning '" + typeToString(srcType) +
+                  "' from a function with result type '" + typeToString(currentFunction_->returnType.get()) +
+                  "' [-Wint-conversion]");
+    } else if (isIntegerType(destType) && isIntegerType(srcType)) {
+        checkConstantConversion(destType, node.value.get(), node.line, node.column);
+    }
 }
 
 // EN: Analyzes a labeled statement body.
@@ -193,4 +193,43 @@ bool SemanticAnalyzer::canAssign(AST::Type* target, AST::Type* source, bool ) {
     return false;
 }
 
+// EN: Computes the common type of two arithmetic operands (C89 6.2.1.5).
+// FR: Calcule le type commun de deux operandes arithmetiques (C89 6.2.1.5).
+AST::Ptr<AST::Type> SemanticAnalyzer::commonArithmeticType(AST::Type* left, AST::Type* right) {
+    // EN: Orders kinds by conversion rank; integers below int promote to int.
+    // FR: Ordonne les types par rang de conversion; les entiers sous int sont promus en int.
+    auto rank = [this](AST::Type* type) -> AST::PrimitiveKind {
+        auto* prim = dynamic_cast<AST::PrimitiveType*>(stripQualifiers(type));
+        if (!prim) return AST::PrimitiveKind::Int;
+        switch (prim->kind) {
+            case AST::PrimitiveKind::LongDouble:
+            case AST::PrimitiveKind::Double:
+            case AST::PrimitiveKind::Float:
+            case AST::PrimitiveKind::UnsignedLongLong:
+            case AST::PrimitiveKind::LongLong:
+            case AST::PrimitiveKind::UnsignedLong:
+            case AST::PrimitiveKind::Long:
+            case AST::PrimitiveKind::UnsignedInt:
+                return prim->kind;
+            default:
+                return AST::PrimitiveKind::Int;
+        }
+    };
+
+    static const AST::PrimitiveKind order[] = {
+        AST::PrimitiveKind::LongDouble, AST::PrimitiveKind::Double, AST::PrimitiveKind::Float,
+        AST::PrimitiveKind::UnsignedLongLong, AST::PrimitiveKind::LongLong, AST::PrimitiveKind::UnsignedLong,
+        AST::PrimitiveKind::Long, AST::PrimitiveKind::UnsignedInt, AST::PrimitiveKind::Int,
+    };
+
+    AST::PrimitiveKind leftKind = rank(left);
+    AST::PrimitiveKind rightKind = rank(right);
+    for (AST::PrimitiveKind kind : order) {
+        if (leftKind == kind || rightKind == kind) {
+            return AST::make<AST::PrimitiveType>(kind, 0, 0);
+        }
+    }
+    return AST::make<AST::PrimitiveType>(AST::PrimitiveKind::Int, 0, 0);
+}
+
 } 
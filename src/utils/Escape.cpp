@@ -0,0 +1,92 @@
+#include "utils/Escape.hpp"
+
+namespace utils
+{
+    // EN: Canonical table of C89 single-character escapes.
+    // FR: Table canonique des echappements C89 a un caractere.
+    bool simple_escape_value(char letter, int& value)
+    {
+        switch (letter) {
+            case 'a': value = '\a'; return true;
+            case 'b': value = '\b'; return true;
+            case 'f': value = '\f'; return true;
+            case 'n': value = '\n'; return true;
+            case 'r': value = '\r'; return true;
+            case 't': value = '\t'; return true;
+            case 'v': value = '\v'; return true;
+            case '\\': value = '\\'; return true;
+            case '\'': value = '\''; return true;
+            case '"': value = '"'; return true;
+            case '?': value = '?'; return true;
+            default: return false;
+        }
+    }
+
+    // EN: Decodes one simple, octal (up to 3 digits) or hex escape into a byte value.
+    // FR: Decode un echappement simple, octal (3 chiffres max) ou hexa en valeur d octet.
+    int decode_escape(const std::string& text, size_t& pos)
+    {
+        pos++;
+        if (pos >= text.size()) {
+            return '\\';
+        }
+
+        char letter = text[pos];
+        int value = 0;
+        if (simple_escape_value(letter, value)) {
+            pos++;
+            return value;
+        }
+
+        if (letter >= '0' && letter <= '7') {
+            int digits = 0;
+            while (pos < text.size() && digits < 3 && text[pos] >= '0' && text[pos] <= '7') {
+                value = value * 8 + (text[pos] - '0');
+                pos++;
+                digits++;
+            }
+            return value & 0xFF;
+        }
+
+        if (letter == 'x') {
+            size_t start = ++pos;
+            while (pos < text.size()) {
+                char c = text[pos];
+                int digit;
+                if (c >= '0' && c <= '9') {
+                    digit = c - '0';
+                } else if (c >= 'a' && c <= 'f') {
+                    digit = c - 'a' + 10;
+                } else if (c >= 'A' && c <= 'F') {
+                    digit = c - 'A' + 10;
+                } else {
+                    break;
+                }
+                value = (value * 16 + digit) & 0xFF;
+                pos++;
+            }
+            return pos == start ? 'x' : value;
+        }
+
+        pos++;
+        return static_cast<unsigned char>(letter);
+    }
+
+    // EN: Decodes a literal body, dropping backslash-newline splices.
+    // FR: Decode un corps de litteral, en retirant les raccords backslash-newline.
+    std::string decode_escapes(const std::string& text)
+    {
+        std::string bytes;
+        size_t pos = 0;
+        while (pos < text.size()) {
+            if (text[pos] != '\\') {
+                bytes += text[pos++];
+            } else if (pos + 1 < text.size() && text[pos + 1] == '\n') {
+                pos += 2;
+            } else {
+                bytes += static_cast<char>(decode_escape(text, pos));
+            }
+        }
+        return bytes;
+    }
+}